@@ -0,0 +1,117 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+use bfbfe_runtime::snapshot::Snapshot;
+use color_eyre::eyre::bail;
+use color_eyre::Report;
+
+/// Counts the bytes of input the program consumes, which a snapshot records
+/// in place of the input itself.
+struct Counted<R>
+{
+    inner:    R,
+    consumed: usize,
+}
+
+impl<R: Read> Read for Counted<R>
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>
+    {
+        let read = self.inner.read(buf)?;
+        self.consumed += read;
+        Ok(read)
+    }
+}
+
+/// Interprets `bytecode` from the start, saving a snapshot to `arg_checkpoint`
+/// every `arg_interval` operations.
+pub fn run(bytecode: &Bytecode, arg_interval: u64, arg_checkpoint: &str) -> Result<(), Report>
+{
+    execute(
+        Snapshot::capture(bytecode, &Interpreter::new(), 0),
+        arg_interval,
+        arg_checkpoint,
+    )
+}
+
+/// Resumes the program saved in the snapshot at `arg_snapshot`, and keeps
+/// checkpointing it to the same file.
+///
+/// The program's input has to be given again from the start, and whatever it
+/// consumed before the snapshot was saved is skipped.
+pub fn resume(arg_interval: u64, arg_snapshot: &str) -> Result<(), Report>
+{
+    let snapshot = Snapshot::read(&mut BufReader::new(File::open(arg_snapshot)?))?;
+    execute(snapshot, arg_interval, arg_snapshot)
+}
+
+fn execute(snapshot: Snapshot, arg_interval: u64, arg_checkpoint: &str) -> Result<(), Report>
+{
+    let mut interpreter = snapshot.restore();
+    let Snapshot {
+        bytecode,
+        mut pc,
+        consumed,
+        output: pending,
+        ..
+    } = snapshot;
+
+    let mut input = Counted {
+        inner: std::io::stdin().lock(),
+        consumed,
+    };
+    let skipped = std::io::copy(
+        &mut input.inner.by_ref().take(u64::try_from(consumed)?),
+        &mut std::io::sink(),
+    )?;
+    if skipped < u64::try_from(consumed)? {
+        bail!("the input ends before where the snapshot left off, {consumed} bytes in");
+    }
+    let mut output = BufWriter::new(std::io::stdout().lock());
+    output.write_all(&pending)?;
+
+    while let Some(next) = interpreter.run_for(&bytecode, pc, arg_interval, &mut input, &mut output)? {
+        pc = next;
+
+        // Everything output so far is written before saving, so a snapshot
+        // never holds pending output of its own
+        output.flush()?;
+        save(
+            &Snapshot {
+                consumed: input.consumed,
+                ..Snapshot::capture(&bytecode, &interpreter, pc)
+            },
+            arg_checkpoint,
+        )?;
+    }
+
+    // The program finished, so there is nothing left to resume
+    match fs::remove_file(arg_checkpoint) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Writes `snapshot` beside `path` and renames it over `path` once it is on
+/// disk, so that being stopped midway never leaves a partial snapshot behind.
+fn save(snapshot: &Snapshot, path: &str) -> Result<(), Report>
+{
+    let temporary = format!("{path}.tmp");
+
+    let file = File::create(&temporary)?;
+    snapshot.write(&mut BufWriter::new(&file))?;
+    file.sync_all()?;
+
+    fs::rename(temporary, path)?;
+
+    Ok(())
+}
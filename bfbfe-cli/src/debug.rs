@@ -0,0 +1,296 @@
+use core::fmt::Write as _;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_lang::location::Location;
+use bfbfe_runtime::debugger::Debugger;
+use bfbfe_runtime::debugger::Stop;
+use bfbfe_runtime::trace::Trace;
+use color_eyre::Report;
+use owo_colors::OwoColorize;
+
+const HELP: &str = "Commands:
+  step [N], s [N]           Execute N operations, or a single one
+  next, n                   Execute the next operation, or the whole loop it begins
+  continue, c               Run until a breakpoint, a watchpoint or the end of the program
+  break [LINE[:COLUMN]], b  Set a breakpoint, or list all breakpoints
+  delete LINE[:COLUMN], d   Remove a breakpoint
+  watch [CELL], w           Watch a cell for changes, or list all watchpoints
+  unwatch CELL              Stop watching a cell
+  tape [RADIUS], t          Show the cells around the pointer
+  where, l                  Show the next operation and where it is in the source
+  help, h                   Show this message
+  quit, q                   Exit the debugger
+
+An empty line repeats the previous command.";
+
+/// How many cells to either side of the pointer are shown by default.
+pub const TAPE_RADIUS: usize = 8;
+
+fn parse_location(s: &str) -> Option<Location>
+{
+    let (line, column) = s.split_once(':').unwrap_or((s, "1"));
+    Some(Location {
+        line:   line.parse().ok()?,
+        column: column.parse().ok()?,
+    })
+}
+
+/// Prints the next operation along with the line of source it came from.
+fn show_location(debugger: &Debugger, lines: &[&str])
+{
+    let (Some(op), Some(location)) = (debugger.op(), debugger.location()) else {
+        println!("Program finished after {} operations", debugger.steps());
+        return;
+    };
+
+    println!("{} {op}", format!("{location}:").bold());
+
+    if let Some(line) = lines.get(location.line.saturating_sub(1)) {
+        // Keep tabs so the marker lines up with the source
+        let padding = line
+            .chars()
+            .take(location.column.saturating_sub(1))
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        println!("{:>6} | {line}", location.line);
+        println!("{:>6} | {padding}{}", "", "^".bright_green().bold());
+    }
+}
+
+/// Prints the cells within `radius` of the pointer. The cell under the pointer
+/// is bracketed, and watched cells are marked with an asterisk.
+pub fn show_tape(tape: &[u8], ptr: usize, watchpoints: &[usize], radius: usize)
+{
+    let start = ptr.saturating_sub(radius);
+    let end = ptr.saturating_add(radius).saturating_add(1).min(tape.len());
+
+    let Some(cells) = tape.get(start..end).filter(|cells| !cells.is_empty()) else {
+        println!("The pointer is outside of the tape, at cell {ptr}");
+        return;
+    };
+
+    let mut indices = String::from("cell ");
+    let mut values = String::from("value");
+    for (index, value) in (start..).zip(cells) {
+        let watched = if watchpoints.contains(&index) { "*" } else { "" };
+        let value = if index == ptr {
+            format!("[{value}]")
+        } else {
+            value.to_string()
+        };
+        let _ = write!(indices, " {:>6}", format!("{index}{watched}"));
+        let _ = write!(values, " {value:>6}");
+    }
+
+    println!("{indices}");
+    println!("{values}");
+}
+
+fn show_stop(stop: Stop)
+{
+    match stop {
+        Stop::Breakpoint(location) => println!("{} at {location}", "Breakpoint".bright_red().bold()),
+
+        Stop::Watchpoint {
+            cell,
+            old,
+            new,
+        } => println!(
+            "{} cell {cell} changed from {old} to {new}",
+            "Watchpoint".bright_red().bold()
+        ),
+
+        Stop::Stepped | Stop::Finished => {}
+    }
+}
+
+/// Executes a single debugger command, returning whether the debugger should
+/// exit.
+fn execute(debugger: &mut Debugger, lines: &[&str], command: &str, input: &mut dyn Read, output: &mut dyn Write)
+    -> bool
+{
+    let mut words = command.split_whitespace();
+    let (Some(name), argument) = (words.next(), words.next()) else {
+        return false;
+    };
+
+    let stop = match (name, argument) {
+        ("step" | "s", count) => {
+            let Some(count) = count.map_or(Some(1), |count| count.parse().ok()) else {
+                println!("Expected an amount of operations to execute");
+                return false;
+            };
+            debugger.step(count, input, output)
+        }
+
+        ("next" | "n", None) => debugger.step_over(input, output),
+
+        ("continue" | "c", None) => debugger.run(input, output),
+
+        ("break" | "b", None) => {
+            let mut breakpoints = debugger.breakpoints().peekable();
+            if breakpoints.peek().is_none() {
+                println!("No breakpoints are set");
+            }
+            breakpoints.for_each(|location| println!("Breakpoint at {location}"));
+            return false;
+        }
+
+        ("break" | "b", Some(location)) => {
+            match parse_location(location).map(|location| debugger.add_breakpoint(location)) {
+                Some(Some(location)) => println!("Breakpoint set at {location}"),
+                Some(None) => println!("There are no instructions at or after {location}"),
+                None => println!("Expected a location such as 3:14"),
+            }
+            return false;
+        }
+
+        ("delete" | "d", Some(location)) => {
+            match parse_location(location).map(|location| debugger.remove_breakpoint(location)) {
+                Some(true) => println!("Breakpoint at {location} removed"),
+                Some(false) => println!("There is no breakpoint at {location}"),
+                None => println!("Expected a location such as 3:14"),
+            }
+            return false;
+        }
+
+        ("watch" | "w", None) => {
+            if debugger.watchpoints().is_empty() {
+                println!("No cells are being watched");
+            }
+            debugger
+                .watchpoints()
+                .iter()
+                .for_each(|cell| println!("Watching cell {cell}"));
+            return false;
+        }
+
+        ("watch" | "w", Some(cell)) => {
+            match cell.parse().map(|cell| debugger.add_watchpoint(cell)) {
+                Ok(true) => println!("Watching cell {cell}"),
+                Ok(false) => println!("Cell {cell} is outside of the tape"),
+                Err(_) => println!("Expected the index of a cell"),
+            }
+            return false;
+        }
+
+        ("unwatch", Some(cell)) => {
+            match cell.parse().map(|cell| debugger.remove_watchpoint(cell)) {
+                Ok(true) => println!("No longer watching cell {cell}"),
+                Ok(false) => println!("Cell {cell} is not being watched"),
+                Err(_) => println!("Expected the index of a cell"),
+            }
+            return false;
+        }
+
+        ("tape" | "t", radius) => {
+            match radius.map_or(Ok(TAPE_RADIUS), str::parse) {
+                Ok(radius) => show_tape(debugger.tape(), debugger.ptr(), debugger.watchpoints(), radius),
+                Err(_) => println!("Expected an amount of cells"),
+            }
+            return false;
+        }
+
+        ("where" | "l", None) => {
+            show_location(debugger, lines);
+            return false;
+        }
+
+        ("help" | "h", None) => {
+            println!("{HELP}");
+            return false;
+        }
+
+        ("quit" | "q", None) => {
+            return true;
+        }
+
+        _ => {
+            println!("Unrecognized command, try `help`");
+            return false;
+        }
+    };
+
+    match stop {
+        Ok(stop) => show_stop(stop),
+        Err(e) => println!("{} {e}", "Error:".bright_red().bold()),
+    }
+    show_location(debugger, lines);
+
+    false
+}
+
+/// Runs an interactive debugger over the program at `arg_input`. Its input is
+/// read from `arg_program_input` if given, and otherwise shares stdin with the
+/// debugger's commands.
+pub fn debug(arg_dialect: Dialect, arg_program_input: Option<String>, arg_input: String) -> Result<(), Report>
+{
+    let source = crate::load(arg_input)?;
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut debugger = Debugger::new(&source, arg_dialect)?;
+
+    let mut input: Box<dyn Read> = match arg_program_input {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(std::io::stdin()),
+    };
+    let mut output = std::io::stdout();
+
+    prompt(&mut debugger, &lines, &mut input, &mut output)
+}
+
+/// Replays the trace at `arg_trace` up to `arg_step`, or to its end, and hands
+/// the program over to the debugger from there. The program keeps reading
+/// from the input that was recorded.
+pub fn replay(arg_step: Option<usize>, arg_trace: String) -> Result<(), Report>
+{
+    let trace = Trace::read(&mut BufReader::new(File::open(arg_trace)?))?;
+    let lines = trace.source.lines().collect::<Vec<_>>();
+
+    let steps = trace.steps.len();
+    let step = arg_step.unwrap_or(steps).min(steps);
+
+    let mut input = trace.input.as_slice();
+    let mut output = std::io::stdout();
+    let mut debugger = trace.seek(step, &mut input, &mut output)?;
+
+    println!();
+    println!("Replayed {step} of {steps} recorded steps");
+    show_tape(debugger.tape(), debugger.ptr(), debugger.watchpoints(), TAPE_RADIUS);
+
+    prompt(&mut debugger, &lines, &mut input, &mut output)
+}
+
+/// Reads and executes commands until the user quits or stdin ends.
+fn prompt(debugger: &mut Debugger, lines: &[&str], input: &mut dyn Read, output: &mut dyn Write) -> Result<(), Report>
+{
+    println!("Type `help` for a list of commands");
+    show_location(debugger, lines);
+
+    let mut previous = String::new();
+    loop {
+        print!("(bfbfe) ");
+        output.flush()?;
+
+        let mut command = String::new();
+        if std::io::stdin().read_line(&mut command)? == 0 {
+            break;
+        }
+
+        if command.trim().is_empty() {
+            command.clone_from(&previous);
+        }
+
+        if execute(debugger, lines, &command, input, output) {
+            break;
+        }
+
+        previous = command;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,10 @@
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+mod checkpoint;
+mod debug;
+mod repl;
+
+use core::time::Duration;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::BufWriter;
@@ -8,8 +13,15 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::time::Instant;
 
+use bfbfe_ir::block::IRBlock;
 use bfbfe_lang::instructionize;
 use bfbfe_lang::lexer;
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+use bfbfe_runtime::limits::ExecutionLimits;
+use bfbfe_runtime::profiler::Profile;
+use bfbfe_runtime::trace::Trace;
 use bfbfe_transpile::backend;
 use bfbfe_transpile::backend::CompilerBackend;
 use clap::value_parser;
@@ -34,17 +46,38 @@ fn parse_arguments() -> ArgMatches
 Components:
 - bfbfe-lang:\t\t{}
 - bfbfe-transpile:\t{}
+- bfbfe-runtime:\t{}
 - bfbfe-ir:\t\t{}",
             bfbfe_lang::VERSION,
             bfbfe_transpile::VERSION,
+            bfbfe_runtime::VERSION,
             bfbfe_ir::VERSION
         ))
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
         .arg(
             Arg::new("quiet")
                 .long("quiet")
                 .short('q')
                 .required(false)
                 .help("Don't display compiler performance metrics")
+                .global(true)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("debug-token")
+                .long("debug-token")
+                .required(false)
+                .help("Recognize # as an instruction that dumps the tape, or breaks into the debugger")
+                .global(true)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("release")
+                .long("release")
+                .required(false)
+                .help("Strip debug instructions from the program")
+                .global(true)
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -75,13 +108,211 @@ Components:
                 )
                 .action(ArgAction::Set),
         )
+        .subcommand(
+            Command::new("run")
+                .about("Execute a Brainfuck program")
+                .arg(
+                    Arg::new("jit")
+                        .long("jit")
+                        .required(false)
+                        .help("Compile the program to native code instead of interpreting it")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("profile")
+                        .long("profile")
+                        .required(false)
+                        .help("Count how often every instruction runs, and report the hottest loops")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with_all(["jit", "max-steps", "max-output", "max-cells", "timeout"]),
+                )
+                .arg(
+                    Arg::new("trace")
+                        .long("trace")
+                        .value_name("FILE")
+                        .required(false)
+                        .help("Record every instruction executed and all input consumed to a trace file")
+                        .action(ArgAction::Set)
+                        .conflicts_with_all(["jit", "profile", "max-steps", "max-output", "max-cells", "timeout"]),
+                )
+                .arg(
+                    Arg::new("checkpoint")
+                        .long("checkpoint")
+                        .value_name("FILE")
+                        .required(false)
+                        .long_help(
+                            "Periodically save the state of the program to a snapshot file, which `resume` continues \
+                             from\n\nThe snapshot is removed once the program finishes",
+                        )
+                        .action(ArgAction::Set)
+                        .conflicts_with_all([
+                            "jit",
+                            "profile",
+                            "trace",
+                            "max-steps",
+                            "max-output",
+                            "max-cells",
+                            "timeout",
+                        ]),
+                )
+                .arg(checkpoint_interval().requires("checkpoint"))
+                .arg(
+                    Arg::new("max-steps")
+                        .long("max-steps")
+                        .value_name("STEPS")
+                        .required(false)
+                        .help("Stop the program once it has executed this many operations")
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(u64))
+                        .conflicts_with("jit"),
+                )
+                .arg(
+                    Arg::new("max-output")
+                        .long("max-output")
+                        .value_name("BYTES")
+                        .required(false)
+                        .help("Stop the program once it has output this many bytes")
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(u64))
+                        .conflicts_with("jit"),
+                )
+                .arg(
+                    Arg::new("max-cells")
+                        .long("max-cells")
+                        .value_name("CELLS")
+                        .required(false)
+                        .help("Stop the program once it has touched this many distinct tape cells")
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(usize))
+                        .conflicts_with("jit"),
+                )
+                .arg(
+                    Arg::new("timeout")
+                        .long("timeout")
+                        .value_name("SECONDS")
+                        .required(false)
+                        .help("Stop the program once it has run for this long")
+                        .action(ArgAction::Set)
+                        .value_parser(parse_timeout)
+                        .conflicts_with("jit"),
+                )
+                .arg(
+                    Arg::new("input")
+                        .required(true)
+                        .help("The location to read the Brainfuck program from")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(
+            Command::new("resume")
+                .about("Resume a program from a snapshot saved by `run --checkpoint`")
+                .long_about(
+                    "Resume a program from a snapshot saved by `run --checkpoint`\n\nThe program's input has to be \
+                     given again from the start, and whatever it had consumed is skipped",
+                )
+                .arg(checkpoint_interval())
+                .arg(
+                    Arg::new("snapshot")
+                        .required(true)
+                        .help("The location of the snapshot, which keeps being checkpointed to")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(
+            Command::new("replay")
+                .about("Replay a trace recorded with `run --trace` in the debugger")
+                .arg(
+                    Arg::new("step")
+                        .long("step")
+                        .value_name("STEP")
+                        .required(false)
+                        .help("Stop after this many instructions instead of at the end of the trace")
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("trace")
+                        .required(true)
+                        .help("The location to read the trace from")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(Command::new("repl").about("Run Brainfuck a line at a time against a persistent tape"))
+        .subcommand(
+            Command::new("debug")
+                .about("Step through a Brainfuck program interactively")
+                .arg(
+                    Arg::new("program-input")
+                        .long("input")
+                        .value_name("FILE")
+                        .required(false)
+                        .long_help(
+                            "Read the program's input from a file\n\nOtherwise, the program reads from stdin along \
+                             with the debugger's commands",
+                        )
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("input")
+                        .required(true)
+                        .help("The location to read the Brainfuck program from")
+                        .action(ArgAction::Set),
+                ),
+        )
         .get_matches()
 }
 
-fn transpile(arg_quiet: bool, arg_target: &CompilerBackend, arg_output: String, arg_input: String)
-    -> Result<(), Report>
+fn checkpoint_interval() -> Arg
+{
+    Arg::new("checkpoint-interval")
+        .long("checkpoint-interval")
+        .value_name("STEPS")
+        .required(false)
+        .help("Save a snapshot every time this many operations have been executed")
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(u64).range(1..))
+        .default_value("1000000000")
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String>
+{
+    let seconds = s.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Time spent in each stage of building a program, in microseconds.
+struct BuildTimes
+{
+    tokenizing:       usize,
+    instructionizing: usize,
+    optimizing:       usize,
+}
+
+impl BuildTimes
+{
+    fn report(&self, compilation_time: usize)
+    {
+        use owo_colors::OwoColorize;
+
+        let Self {
+            tokenizing: tokenizing_time,
+            instructionizing: instructionizing_time,
+            optimizing: optimizing_time,
+        } = *self;
+
+        let total_time = tokenizing_time + instructionizing_time + optimizing_time + compilation_time;
+        eprintln!("{} in {total_time} \u{3bc}s", "Build complete".bright_green().bold());
+        eprintln!("{}\t\t{tokenizing_time} \u{3bc}s", "Tokenizing".underline());
+        eprintln!("{}\t{instructionizing_time} \u{3bc}s", "Instructionizing".underline());
+        eprintln!("{}\t\t{optimizing_time} \u{3bc}s", "Optimizing".underline());
+        eprintln!("{}\t\t{compilation_time} \u{3bc}s", "Compiling".underline());
+    }
+}
+
+/// Loads the source of a Brainfuck program from a file, or from stdin if the
+/// path is a single hyphen (-).
+fn load(arg_input: String) -> Result<String, Report>
 {
-    // Load input into String
     let mut input = String::new();
     if arg_input == "-" {
         std::io::stdin().read_to_string(&mut input)?;
@@ -89,24 +320,19 @@ fn transpile(arg_quiet: bool, arg_target: &CompilerBackend, arg_output: String,
         File::open(arg_input)?.read_to_string(&mut input)?;
     }
 
-    // Load output into BufWriter
-    let mut output: BufWriter<Box<dyn Write>> = if arg_output == "-" {
-        BufWriter::new(Box::new(std::io::stdout()))
-    } else {
-        BufWriter::new(Box::new(
-            OpenOptions::new()
-                .create(true)
-                .read(false)
-                .append(false)
-                .write(true)
-                .open(PathBuf::from(arg_output))?,
-        ))
-    };
+    Ok(input)
+}
+
+/// Loads a Brainfuck program and turns it into optimized BFBFE IR, stripping
+/// debug instructions first if `arg_release` is set.
+fn build(arg_dialect: Dialect, arg_release: bool, arg_input: String) -> Result<(IRBlock, BuildTimes), Report>
+{
+    let input = load(arg_input)?;
 
     // Tokenizing
     let (tokens, tokenizing_time) = {
         let inst = Instant::now();
-        let tokens = lexer::tokenize_whole_program(&input.chars().collect_vec());
+        let tokens = lexer::tokenize_dialect(&input.chars().collect_vec(), arg_dialect);
         let elapsed = inst.elapsed();
         let tokenizing_time = usize::try_from(elapsed.as_micros())?;
         (tokens, tokenizing_time)
@@ -115,7 +341,10 @@ fn transpile(arg_quiet: bool, arg_target: &CompilerBackend, arg_output: String,
     // Instructionizing
     let (mut block, instructionizing_time) = {
         let inst = Instant::now();
-        let block = instructionize::instructionize(&tokens)?;
+        let mut block = instructionize::instructionize(&tokens)?;
+        if arg_release {
+            block = block.strip_debug();
+        }
         let elapsed = inst.elapsed();
         let instructionizing_time = usize::try_from(elapsed.as_micros())?;
         (block, instructionizing_time)
@@ -129,32 +358,221 @@ fn transpile(arg_quiet: bool, arg_target: &CompilerBackend, arg_output: String,
         usize::try_from(elapsed.as_micros())?
     };
 
+    Ok((
+        block,
+        BuildTimes {
+            tokenizing:       tokenizing_time,
+            instructionizing: instructionizing_time,
+            optimizing:       optimizing_time,
+        },
+    ))
+}
+
+fn transpile(
+    arg_quiet: bool,
+    arg_dialect: Dialect,
+    arg_release: bool,
+    arg_target: &CompilerBackend,
+    arg_output: String,
+    arg_input: String,
+) -> Result<(), Report>
+{
+    // Load output into BufWriter
+    let mut output: BufWriter<Box<dyn Write>> = if arg_output == "-" {
+        BufWriter::new(Box::new(std::io::stdout()))
+    } else {
+        BufWriter::new(Box::new(
+            OpenOptions::new()
+                .create(true)
+                .read(false)
+                .append(false)
+                .write(true)
+                .truncate(true)
+                .open(PathBuf::from(arg_output))?,
+        ))
+    };
+
+    let (block, build_times) = build(arg_dialect, arg_release, arg_input)?;
+
     // Compiling
     let (program, compilation_time) = {
         let backend_func = backend::get_compiler_fn(arg_target);
         let inst = Instant::now();
-        let program = backend_func(&block);
+        let program = backend_func(&block)?;
         let elapsed = inst.elapsed();
         let compilation_time = usize::try_from(elapsed.as_micros())?;
         (program, compilation_time)
     };
 
     if !arg_quiet {
-        use owo_colors::OwoColorize;
+        build_times.report(compilation_time);
+    }
 
-        let total_time = tokenizing_time + instructionizing_time + optimizing_time + compilation_time;
-        eprintln!("{} in {total_time} \u{3bc}s", "Build complete".bright_green().bold());
-        eprintln!("{}\t\t{tokenizing_time} \u{3bc}s", "Tokenizing".underline());
-        eprintln!("{}\t{instructionizing_time} \u{3bc}s", "Instructionizing".underline());
-        eprintln!("{}\t\t{optimizing_time} \u{3bc}s", "Optimizing".underline());
-        eprintln!("{}\t\t{compilation_time} \u{3bc}s", "Compiling".underline());
+    output.write_all(&program)?;
+
+    Ok(())
+}
+
+/// How many loops are listed by `run --profile`.
+const PROFILED_LOOPS: usize = 10;
+
+/// How `run` executes a program.
+enum RunMode
+{
+    Interpret(ExecutionLimits),
+    Checkpoint
+    {
+        path:     String,
+        interval: u64,
+    },
+    Jit,
+    Profile,
+    Trace(String),
+}
+
+fn run(
+    arg_quiet: bool,
+    arg_dialect: Dialect,
+    arg_release: bool,
+    arg_mode: RunMode,
+    arg_input: String,
+) -> Result<(), Report>
+{
+    match arg_mode {
+        RunMode::Interpret(_)
+        | RunMode::Checkpoint {
+            ..
+        } => {}
+        RunMode::Profile => return profile(arg_dialect, arg_input),
+        RunMode::Trace(arg_trace) => return trace(arg_dialect, arg_trace, arg_input),
+        RunMode::Jit => {
+            let (block, build_times) = build(arg_dialect, arg_release, arg_input)?;
+            return run_jit(arg_quiet, &block, &build_times);
+        }
+    }
+
+    let (block, build_times) = build(arg_dialect, arg_release, arg_input)?;
+
+    // Compiling
+    let (bytecode, compilation_time) = {
+        let inst = Instant::now();
+        let bytecode = Bytecode::compile(&block)?;
+        let elapsed = inst.elapsed();
+        let compilation_time = usize::try_from(elapsed.as_micros())?;
+        (bytecode, compilation_time)
+    };
+
+    if !arg_quiet {
+        build_times.report(compilation_time);
+    }
+
+    if let RunMode::Checkpoint {
+        path: arg_checkpoint,
+        interval: arg_interval,
+    } = arg_mode
+    {
+        return checkpoint::run(&bytecode, arg_interval, &arg_checkpoint);
+    }
+
+    if let RunMode::Interpret(arg_limits) = arg_mode {
+        Interpreter::<u8>::new().run_with_limits(
+            &bytecode,
+            &mut std::io::stdin().lock(),
+            &mut BufWriter::new(std::io::stdout().lock()),
+            &arg_limits,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Runs a program unoptimized while counting every instruction, then reports
+/// the loops most instructions were spent in.
+fn profile(arg_dialect: Dialect, arg_input: String) -> Result<(), Report>
+{
+    use owo_colors::OwoColorize;
+
+    let source = load(arg_input)?;
+    let profile = Profile::run(
+        &source,
+        arg_dialect,
+        &mut std::io::stdin().lock(),
+        &mut BufWriter::new(std::io::stdout().lock()),
+    )?;
+
+    eprintln!(
+        "{} after {} instructions",
+        "Profile complete".bright_green().bold(),
+        profile.instructions()
+    );
+    eprintln!(
+        "{}",
+        format!(
+            "{:>14}  {:>12}  {:<20}  Optimization",
+            "Instructions", "Iterations", "Loop"
+        )
+        .underline()
+    );
+    for hot in profile.hottest_loops().into_iter().take(PROFILED_LOOPS) {
+        let idiom = hot.idiom.map_or_else(|| "none".to_owned(), |idiom| idiom.to_string());
+        eprintln!(
+            "{:>14}  {:>12}  {:<20}  {idiom}",
+            hot.instructions,
+            hot.iterations,
+            hot.span.to_string()
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs a program unoptimized while recording a trace of it to `arg_trace`.
+fn trace(arg_dialect: Dialect, arg_trace: String, arg_input: String) -> Result<(), Report>
+{
+    let source = load(arg_input)?;
+    Trace::record(
+        &source,
+        arg_dialect,
+        &mut std::io::stdin().lock(),
+        &mut BufWriter::new(std::io::stdout().lock()),
+        &mut BufWriter::new(File::create(arg_trace)?),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(feature = "jit")]
+fn run_jit(arg_quiet: bool, block: &IRBlock, build_times: &BuildTimes) -> Result<(), Report>
+{
+    use bfbfe_runtime::jit::JitProgram;
+
+    // Compiling
+    let (program, compilation_time) = {
+        let inst = Instant::now();
+        let program = JitProgram::compile(block)?;
+        let elapsed = inst.elapsed();
+        let compilation_time = usize::try_from(elapsed.as_micros())?;
+        (program, compilation_time)
+    };
+
+    if !arg_quiet {
+        build_times.report(compilation_time);
     }
 
-    output.write_all(program.as_bytes())?;
+    program.run(
+        &mut std::io::stdin().lock(),
+        &mut BufWriter::new(std::io::stdout().lock()),
+    )?;
 
     Ok(())
 }
 
+#[cfg(not(feature = "jit"))]
+fn run_jit(_arg_quiet: bool, _block: &IRBlock, _build_times: &BuildTimes) -> Result<(), Report>
+{
+    color_eyre::eyre::bail!("JIT support is not available, rebuild bfbfe-cli with the jit feature enabled");
+}
+
 pub fn main() -> Result<()>
 {
     color_eyre::install()?;
@@ -162,11 +580,58 @@ pub fn main() -> Result<()>
     let args = parse_arguments();
 
     let arg_quiet = *args.get_one::<bool>("quiet").unwrap();
-    let arg_target = args.get_one::<CompilerBackend>("target").unwrap();
-    let arg_output = args.get_one::<String>("output").unwrap().clone();
-    let arg_input = args.get_one::<String>("input").unwrap().clone();
+    let arg_dialect = Dialect {
+        debug: *args.get_one::<bool>("debug-token").unwrap(),
+    };
+    let arg_release = *args.get_one::<bool>("release").unwrap();
+
+    if let Some(("run", args)) = args.subcommand() {
+        let arg_mode = if *args.get_one::<bool>("jit").unwrap() {
+            RunMode::Jit
+        } else if *args.get_one::<bool>("profile").unwrap() {
+            RunMode::Profile
+        } else if let Some(arg_trace) = args.get_one::<String>("trace") {
+            RunMode::Trace(arg_trace.clone())
+        } else if let Some(arg_checkpoint) = args.get_one::<String>("checkpoint") {
+            RunMode::Checkpoint {
+                path:     arg_checkpoint.clone(),
+                interval: *args.get_one::<u64>("checkpoint-interval").unwrap(),
+            }
+        } else {
+            RunMode::Interpret(ExecutionLimits {
+                max_steps:  args.get_one::<u64>("max-steps").copied(),
+                max_output: args.get_one::<u64>("max-output").copied(),
+                max_cells:  args.get_one::<usize>("max-cells").copied(),
+                timeout:    args.get_one::<Duration>("timeout").copied(),
+            })
+        };
+        let arg_input = args.get_one::<String>("input").unwrap().clone();
+
+        run(arg_quiet, arg_dialect, arg_release, arg_mode, arg_input)?;
+    } else if let Some(("debug", args)) = args.subcommand() {
+        let arg_program_input = args.get_one::<String>("program-input").cloned();
+        let arg_input = args.get_one::<String>("input").unwrap().clone();
+
+        debug::debug(arg_dialect, arg_program_input, arg_input)?;
+    } else if let Some(("resume", args)) = args.subcommand() {
+        let arg_interval = *args.get_one::<u64>("checkpoint-interval").unwrap();
+        let arg_snapshot = args.get_one::<String>("snapshot").unwrap().clone();
 
-    transpile(arg_quiet, arg_target, arg_output, arg_input)?;
+        checkpoint::resume(arg_interval, &arg_snapshot)?;
+    } else if let Some(("repl", _)) = args.subcommand() {
+        repl::repl(arg_dialect)?;
+    } else if let Some(("replay", args)) = args.subcommand() {
+        let arg_step = args.get_one::<usize>("step").copied();
+        let arg_trace = args.get_one::<String>("trace").unwrap().clone();
+
+        debug::replay(arg_step, arg_trace)?;
+    } else {
+        let arg_target = args.get_one::<CompilerBackend>("target").unwrap();
+        let arg_output = args.get_one::<String>("output").unwrap().clone();
+        let arg_input = args.get_one::<String>("input").unwrap().clone();
+
+        transpile(arg_quiet, arg_dialect, arg_release, arg_target, arg_output, arg_input)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,103 @@
+use std::io::Stdout;
+use std::io::Write;
+
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::instructionize::InstructionizingError;
+use bfbfe_lang::lexer;
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+use color_eyre::Report;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use crate::debug::show_tape;
+use crate::debug::TAPE_RADIUS;
+
+const PROMPT: &str = "bfbfe> ";
+
+/// Shown instead of the prompt while a loop is left open.
+const CONTINUATION: &str = "  ...> ";
+
+/// Passes output through to stdout, remembering whether it ended in the middle
+/// of a line.
+struct Output
+{
+    inner:    Stdout,
+    mid_line: bool,
+}
+
+impl Write for Output
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>
+    {
+        let written = self.inner.write(buf)?;
+        if let Some(&last) = buf.get(..written).and_then(<[u8]>::last) {
+            self.mid_line = last != b'\n';
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()>
+    {
+        self.inner.flush()
+    }
+}
+
+/// Reads Brainfuck a line at a time and runs every line against the same tape,
+/// showing the cells around the pointer afterwards. Lines that leave a loop
+/// open are joined with the following lines until it is closed.
+pub fn repl(arg_dialect: Dialect) -> Result<(), Report>
+{
+    println!("Every line runs against the same tape, end the input to exit");
+
+    let mut interpreter = Interpreter::<u8>::new();
+    let mut output = Output {
+        inner:    std::io::stdout(),
+        mid_line: false,
+    };
+
+    let mut source = String::new();
+    loop {
+        print!("{}", if source.is_empty() { PROMPT } else { CONTINUATION });
+        output.flush()?;
+
+        if std::io::stdin().read_line(&mut source)? == 0 {
+            println!();
+            break;
+        }
+
+        let tokens = lexer::tokenize_dialect(&source.chars().collect_vec(), arg_dialect);
+        let mut block = match instructionize(&tokens) {
+            Ok(block) => block,
+            Err(InstructionizingError::UnmatchedJumpForward) => continue,
+            Err(e) => {
+                println!("{} {e}", "Error:".bright_red().bold());
+                source.clear();
+                continue;
+            }
+        };
+        source.clear();
+
+        // Lines run against the tape earlier lines left behind, so they must
+        // not be optimized as programs starting from a zeroed tape
+        block.retain(|instr| !matches!(instr, IRInstruction::BeginProgram | IRInstruction::EndProgram));
+
+        // Programs share stdin with the REPL, so reads take the next line
+        let result = Bytecode::compile(&block.optimize())
+            .and_then(|bytecode| interpreter.run(&bytecode, &mut std::io::stdin(), &mut output));
+
+        if output.mid_line {
+            println!();
+            output.mid_line = false;
+        }
+        if let Err(e) = result {
+            println!("{} {e}", "Error:".bright_red().bold());
+        }
+
+        show_tape(interpreter.tape(), interpreter.ptr(), &[], TAPE_RADIUS);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![allow(clippy::std_instead_of_core)]
+
+//! Stops a checkpointed program partway through its input, and resumes it
+//! from its snapshot on the same input.
+
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Write;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use bfbfe_runtime::bytecode::Op;
+use bfbfe_runtime::snapshot::Snapshot;
+
+const CLI: &str = env!("CARGO_BIN_EXE_bfbfe-cli");
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn resuming_skips_consumed_input()
+{
+    let dir = std::env::temp_dir().join("bfbfe-checkpoint");
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    let program = dir.join("echo.b");
+    let snapshot = dir.join("echo.bfs");
+    fs::write(&program, ",.,.,.,.").expect("failed to write program");
+    let _ = fs::remove_file(&snapshot);
+
+    // Only half of the input is given, and stdin is kept open, so the
+    // program is left waiting for the rest
+    let mut child = Command::new(CLI)
+        .args(["run", "--quiet", "--checkpoint-interval", "1", "--checkpoint"])
+        .arg(&snapshot)
+        .arg(&program)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run bfbfe-cli");
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    stdin.write_all(b"ab").expect("failed to write input");
+
+    let deadline = Instant::now() + TIMEOUT;
+    loop {
+        let saved = File::open(&snapshot)
+            .ok()
+            .and_then(|file| Snapshot::read(&mut BufReader::new(file)).ok());
+        let waiting = saved.is_some_and(|saved| {
+            saved.consumed == 2 && matches!(saved.bytecode.ops.get(saved.pc), Some(Op::Read { .. }))
+        });
+        if waiting {
+            break;
+        }
+
+        assert!(Instant::now() < deadline, "no snapshot was saved before the third read");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    child.kill().expect("failed to stop bfbfe-cli");
+    drop(stdin);
+    let stopped = child.wait_with_output().expect("failed to wait for bfbfe-cli");
+    assert_eq!(stopped.stdout, b"ab");
+
+    let mut child = Command::new(CLI)
+        .args(["resume", "--quiet"])
+        .arg(&snapshot)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run bfbfe-cli");
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(b"abcd")
+        .expect("failed to write input");
+    let resumed = child.wait_with_output().expect("failed to wait for bfbfe-cli");
+
+    assert!(resumed.status.success(), "resuming failed with {}", resumed.status);
+    assert_eq!(resumed.stdout, b"cd");
+    assert!(
+        !snapshot.exists(),
+        "the snapshot is left behind after the program finished"
+    );
+}
@@ -0,0 +1,161 @@
+use core::ops::Range;
+
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+/// Bounds on what running a block or an instruction once does to the pointer
+/// and which cells it can reach, relative to where the pointer started.
+///
+/// The bounds hold whatever the tape holds, so loops that don't end where
+/// they started leave them open in the direction they move in. Open bounds
+/// are `None`, as are bounds that would overflow.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Footprint
+{
+    /// The lowest position the pointer can end at.
+    pub min_stride: Option<isize>,
+    /// The highest position the pointer can end at.
+    pub max_stride: Option<isize>,
+    /// The lowest position that is accessed or that the pointer moves to.
+    pub min_offset: Option<isize>,
+    /// The highest position that is accessed or that the pointer moves to.
+    pub max_offset: Option<isize>,
+}
+
+/// The lower of two lower bounds, `None` being unbounded.
+fn lower(a: Option<isize>, b: Option<isize>) -> Option<isize>
+{
+    Some(a?.min(b?))
+}
+
+/// The higher of two upper bounds, `None` being unbounded.
+fn upper(a: Option<isize>, b: Option<isize>) -> Option<isize>
+{
+    Some(a?.max(b?))
+}
+
+fn add(a: Option<isize>, b: Option<isize>) -> Option<isize>
+{
+    a?.checked_add(b?)
+}
+
+impl Footprint
+{
+    /// Leaves the pointer where it is and reaches no other cell.
+    pub const fn none() -> Self
+    {
+        Self::reaching(0, 0)
+    }
+
+    /// Leaves the pointer where it is and reaches the cells from `min` to
+    /// `max`, along with the one the pointer is on.
+    const fn reaching(min: isize, max: isize) -> Self
+    {
+        Self {
+            min_stride: Some(0),
+            max_stride: Some(0),
+            min_offset: Some(if min < 0 { min } else { 0 }),
+            max_offset: Some(if max > 0 { max } else { 0 }),
+        }
+    }
+
+    pub fn of(block: &IRBlock) -> Self
+    {
+        block.iter().map(Self::of_instruction).fold(Self::none(), Self::then)
+    }
+
+    /// A `ConditionalBlock` counts as a whole, for however often it runs.
+    /// Running its body once is what `Footprint::of` the body is for.
+    pub fn of_instruction(instr: &IRInstruction) -> Self
+    {
+        match instr {
+            IRInstruction::TraverseBy {
+                val,
+            } => Self {
+                min_stride: Some(*val),
+                max_stride: Some(*val),
+                ..Self::reaching(*val, *val)
+            },
+
+            IRInstruction::MutateValue {
+                pos, ..
+            }
+            | IRInstruction::SetTo {
+                pos, ..
+            } => Self::reaching(*pos, *pos),
+
+            IRInstruction::OutputBytes {
+                poslst,
+            }
+            | IRInstruction::ReadBytes {
+                poslst,
+            } => Self::reaching(
+                poslst.iter().copied().min().unwrap_or_default(),
+                poslst.iter().copied().max().unwrap_or_default(),
+            ),
+
+            IRInstruction::ConditionalBlock(cond, body) => {
+                let body = Self::of(body);
+
+                // Every iteration starts where the ones before it moved the
+                // pointer to, and the loop ends where one would have started
+                let min_start = body.min_stride.filter(|stride| *stride >= 0).map(|_| 0);
+                let max_start = body.max_stride.filter(|stride| *stride <= 0).map(|_| 0);
+
+                Self {
+                    min_stride: min_start,
+                    max_stride: max_start,
+                    min_offset: lower(add(min_start, body.min_offset), add(min_start, Some(*cond))),
+                    max_offset: upper(add(max_start, body.max_offset), add(max_start, Some(*cond))),
+                }
+            }
+
+            IRInstruction::IfNonZero(cond, body) => {
+                let body = Self::of(body);
+
+                Self {
+                    min_stride: lower(Some(0), body.min_stride),
+                    max_stride: upper(Some(0), body.max_stride),
+                    min_offset: lower(body.min_offset, Some(*cond)),
+                    max_offset: upper(body.max_offset, Some(*cond)),
+                }
+            }
+
+            _ => Self::none(),
+        }
+    }
+
+    /// What running `next` right after `self` does.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self
+    {
+        Self {
+            min_stride: add(self.min_stride, next.min_stride),
+            max_stride: add(self.max_stride, next.max_stride),
+            min_offset: lower(self.min_offset, add(self.min_stride, next.min_offset)),
+            max_offset: upper(self.max_offset, add(self.max_stride, next.max_offset)),
+        }
+    }
+
+    /// How far the pointer always moves, if that doesn't depend on the tape.
+    pub fn stride(&self) -> Option<isize>
+    {
+        self.min_stride.filter(|_| self.min_stride == self.max_stride)
+    }
+
+    /// Whether the pointer always ends where it started.
+    pub fn is_balanced(&self) -> bool
+    {
+        self.stride() == Some(0)
+    }
+
+    /// The cells that can be reached when the pointer starts on the first
+    /// one, as it does for a whole program, or `None` if that range is open
+    /// or goes past the start of the tape.
+    pub fn cells(&self) -> Option<Range<usize>>
+    {
+        let start = usize::try_from(self.min_offset?).ok()?;
+        let end = usize::try_from(self.max_offset?).ok()?.checked_add(1)?;
+        Some(start..end)
+    }
+}
@@ -2,8 +2,13 @@ use core::ops::Deref;
 use core::ops::DerefMut;
 
 use super::instruction::IRInstruction;
+use crate::analysis::Footprint;
+use crate::optimization::conditional::ConditionalOptimization;
 use crate::optimization::constants::ConstantsOptimization;
+use crate::optimization::dead::DeadCodeElimination;
+use crate::optimization::evaluate::PartialEvaluation;
 use crate::optimization::merge::MergeInstructions;
+use crate::optimization::propagate::ConstantPropagation;
 use crate::optimization::reorder::ReorderingOptimizations;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -59,19 +64,97 @@ impl IRBlock
         }
     }
 
+    /// Bounds on how the block moves the pointer and which cells it reaches.
+    /// For a whole program, the cells it reaches are those of the tape it
+    /// needs.
+    pub fn footprint(&self) -> Footprint
+    {
+        Footprint::of(self)
+    }
+
+    /// Whether the pointer always ends where it started after running the
+    /// block, which needs every loop inside of it to end where it started as
+    /// well.
+    pub fn is_balanced(&self) -> bool
+    {
+        self.footprint().is_balanced()
+    }
+
+    /// Adds `by` to every position the block accesses, so that it does what
+    /// it did before with the pointer `by` positions further right.
+    pub fn offset_by(&mut self, by: isize)
+    {
+        for instr in &mut self.content {
+            match instr {
+                IRInstruction::MutateValue {
+                    pos, ..
+                }
+                | IRInstruction::SetTo {
+                    pos, ..
+                } => {
+                    *pos += by;
+                }
+
+                IRInstruction::OutputBytes {
+                    poslst,
+                }
+                | IRInstruction::ReadBytes {
+                    poslst,
+                } => {
+                    for pos in poslst {
+                        *pos += by;
+                    }
+                }
+
+                IRInstruction::ConditionalBlock(pos, block) | IRInstruction::IfNonZero(pos, block) => {
+                    *pos += by;
+                    block.offset_by(by);
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    /// Removes every `Debug` instruction. This should happen before
+    /// optimizing, since `Debug` keeps instructions from being merged across
+    /// it.
+    pub fn strip_debug(mut self) -> Self
+    {
+        self.content.retain(|instr| *instr != IRInstruction::Debug);
+
+        for instr in &mut self.content {
+            if let IRInstruction::ConditionalBlock(_, block) | IRInstruction::IfNonZero(_, block) = instr {
+                **block = block.clone().strip_debug();
+            }
+        }
+
+        self
+    }
+
     pub fn optimize(mut self) -> Self
     {
+        // Loops that never run aren't worth optimizing
+        self.remove_dead_loops();
+
         for instr in &mut self.content {
-            if let &mut IRInstruction::ConditionalBlock(ref mut block) = instr {
+            if let IRInstruction::ConditionalBlock(_, block) | IRInstruction::IfNonZero(_, block) = instr {
                 *block = Box::new(block.clone().optimize());
             }
         }
 
         self.merge_instructions();
-        // The find_set_to_zero optimization can cause an extremely rare misoptimization. Feel free to test and uncomment the following line if your program does not get broken.
-        //self.find_set_to_zero();
+        self.find_set_to_zero();
         self.find_set_to_value();
         self.reorder_instructions();
+        self.find_if_non_zero();
+        // Unrolled loops leave their instructions to be merged again
+        self.propagate_constants();
+        self.merge_instructions();
+        self.reorder_instructions();
+        self.remove_dead_stores();
+        self.find_constant_output();
+        self.evaluate_prefix();
 
         self
     }
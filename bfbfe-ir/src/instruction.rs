@@ -46,10 +46,29 @@ pub enum IRInstruction
     {
         poslst: Vec<isize>
     },
+    /// Output `bytes` as they are, without reading them from the tape.
+    OutputLiteral
+    {
+        bytes: Vec<u8>
+    },
 
     // ~~~~~~~~~~~ Blocks ~~~~~~~~~~~~
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    /// A block of instructions to be executed while the currently pointed to
-    /// byte is non-zero.
-    ConditionalBlock(Box<IRBlock>),
+    /// A block of instructions to be executed while the byte `pos` positions
+    /// relative to the pointer is non-zero.
+    ConditionalBlock(isize, Box<IRBlock>),
+    /// A block of instructions to be executed once if the byte `pos`
+    /// positions relative to the pointer is non-zero. The block has to leave
+    /// that byte at zero, relative to wherever the pointer ends, which makes
+    /// it the same as a `ConditionalBlock` that never runs twice.
+    IfNonZero(isize, Box<IRBlock>),
+
+    // ~~~~~~~~~~~ Debugging ~~~~~~~~~~~
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    /// Dump the tape, or break into the debugger. Optimizations don't move
+    /// instructions across it, so the state it shows is exact, and it should
+    /// be stripped from release builds. Only the C backend generates tape
+    /// dumps, while other targets without a debugger skip it, so that debug
+    /// builds still run there.
+    Debug,
 }
@@ -36,6 +36,7 @@
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub mod analysis;
 pub mod block;
 pub mod instruction;
 pub mod optimization;
@@ -0,0 +1,40 @@
+use super::knowledge::Knowledge;
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+pub trait ConditionalOptimization
+{
+    fn find_if_non_zero(&mut self);
+}
+
+/// Whether a block always leaves the cell `cond` positions from where the
+/// pointer ends at zero, such as one ending in `[-]` on that cell or in
+/// another loop on it, which makes a loop of it on `cond` run at most once.
+fn ends_on_zero(block: &IRBlock, cond: isize) -> bool
+{
+    let mut knowledge = Knowledge::unknown();
+    for instr in block.iter() {
+        knowledge.update(instr);
+    }
+
+    knowledge.get(cond) == Some(0)
+}
+
+impl ConditionalOptimization for IRBlock
+{
+    /// Turns `ConditionalBlock`s whose body always leaves the cell they're on
+    /// at zero into `IfNonZero`s, since they never get to run a second time.
+    ///
+    /// Only a cell set to exactly zero counts, rather than one that is zero
+    /// as a byte, so that it holds whatever the width of the cells.
+    fn find_if_non_zero(&mut self)
+    {
+        for instr in &mut self.content {
+            if let IRInstruction::ConditionalBlock(pos, block) = instr {
+                if ends_on_zero(block, *pos) {
+                    *instr = IRInstruction::IfNonZero(*pos, block.clone());
+                }
+            }
+        }
+    }
+}
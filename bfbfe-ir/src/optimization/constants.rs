@@ -1,3 +1,4 @@
+use super::knowledge::Knowledge;
 use crate::block::IRBlock;
 use crate::instruction::IRInstruction;
 
@@ -5,6 +6,7 @@ pub trait ConstantsOptimization
 {
     fn find_set_to_zero(&mut self);
     fn find_set_to_value(&mut self);
+    fn find_constant_output(&mut self);
 }
 
 impl ConstantsOptimization for IRBlock
@@ -12,15 +14,15 @@ impl ConstantsOptimization for IRBlock
     /// Creates `SetTo` instructions by looking for the pattern of a single
     /// `MutateValue` inside a `ConditionalBlock`.
     ///
-    /// It catches any block like this unless the `MutateValue`:
-    ///     * has a value of 0 (always infinite loop)
-    ///     * is a divisor of 256 (sometimes infinite loop)
+    /// It only catches blocks whose `MutateValue` has an odd value. Any other
+    /// value skips over every cell value of the wrong parity, so the loop
+    /// never finishes for some of them.
     fn find_set_to_zero(&mut self)
     {
         let mut buffer: Vec<IRInstruction> = Vec::new();
 
         'a: for instr in &self.content {
-            if let IRInstruction::ConditionalBlock(block) = &instr {
+            if let IRInstruction::ConditionalBlock(cond, block) = &instr {
                 if block.len() == 1 {
                     if let IRInstruction::MutateValue {
                         pos,
@@ -29,14 +31,13 @@ impl ConstantsOptimization for IRBlock
                         // SAFETY: The length was just verified to be 1
                         unsafe { block.get_unchecked(0) }
                     } {
-                        if *pos != 0 {
+                        if pos != cond {
                             continue 'a;
                         }
 
-                        let val = val.abs();
-                        if (val != 0 && 256 % val != 0) || val == 1 {
+                        if val % 2 != 0 {
                             buffer.push(IRInstruction::SetTo {
-                                pos: 0, val: 0
+                                pos: *pos, val: 0
                             });
 
                             continue 'a;
@@ -63,28 +64,93 @@ impl ConstantsOptimization for IRBlock
                 val: set_val,
             } = instr
             {
-                if let Some(IRInstruction::MutateValue {
+                // Every mutation that follows is folded in, while one elsewhere
+                // leaves the SetTo to be kept as is
+                let mut val = *set_val;
+                while let Some(IRInstruction::MutateValue {
                     pos: mut_pos,
                     val: mut_val,
                 }) = pk.peek()
                 {
                     if set_pos != mut_pos {
-                        continue;
+                        break;
                     }
 
-                    buffer.push(IRInstruction::SetTo {
-                        pos: *set_pos,
-                        val: set_val + mut_val,
-                    });
-
+                    val += mut_val;
                     pk.next();
-                    continue;
                 }
+
+                buffer.push(IRInstruction::SetTo {
+                    pos: *set_pos,
+                    val,
+                });
+                continue;
+            }
+
+            buffer.push(instr.clone());
+        }
+
+        self.content = buffer;
+    }
+
+    /// Creates `OutputLiteral` instructions from `OutputBytes` of cells whose
+    /// values are known at that point of the block. Outputs of known and
+    /// unknown cells mixed together are split up, keeping their order.
+    fn find_constant_output(&mut self)
+    {
+        let mut buffer: Vec<IRInstruction> = Vec::new();
+        let mut knowledge = Knowledge::unknown();
+
+        for instr in &self.content {
+            if let IRInstruction::OutputBytes {
+                poslst,
+            } = instr
+            {
+                // Runs of known and unknown cells each become one instruction
+                for pos in poslst {
+                    match knowledge.get(*pos).map(byte) {
+                        Some(value) => {
+                            if let Some(IRInstruction::OutputLiteral {
+                                bytes,
+                            }) = buffer.last_mut()
+                            {
+                                bytes.push(value);
+                            } else {
+                                buffer.push(IRInstruction::OutputLiteral {
+                                    bytes: vec![value]
+                                });
+                            }
+                        }
+
+                        None => {
+                            if let Some(IRInstruction::OutputBytes {
+                                poslst,
+                            }) = buffer.last_mut()
+                            {
+                                poslst.push(*pos);
+                            } else {
+                                buffer.push(IRInstruction::OutputBytes {
+                                    poslst: vec![*pos]
+                                });
+                            }
+                        }
+                    }
+                }
+
+                continue;
             }
 
+            knowledge.update(instr);
             buffer.push(instr.clone());
         }
 
         self.content = buffer;
     }
 }
+
+/// Truncates a cell value to the byte that gets output for it, whatever the
+/// width of the cells.
+fn byte(val: isize) -> u8
+{
+    u8::try_from(val.rem_euclid(256)).unwrap_or_default()
+}
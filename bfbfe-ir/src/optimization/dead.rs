@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+pub trait DeadCodeElimination
+{
+    fn remove_dead_loops(&mut self);
+    fn remove_dead_stores(&mut self);
+}
+
+impl DeadCodeElimination for IRBlock
+{
+    /// Removes `ConditionalBlock`s and `IfNonZero`s that are reached on a cell
+    /// that is always zero, so that they never run: a loop right after
+    /// another loop, which only ever ends on a zero cell, and the comment loop
+    /// many programs start with, since the tape starts out zeroed.
+    ///
+    /// This only looks at what the block does between the loops, so it's
+    /// cheap enough to run before the loops themselves are optimized.
+    fn remove_dead_loops(&mut self)
+    {
+        // The position of a cell known to be zero, relative to the pointer
+        let mut zero = None::<isize>;
+
+        self.content.retain(|instr| {
+            match instr {
+                IRInstruction::ConditionalBlock(pos, _) | IRInstruction::IfNonZero(pos, _) => {
+                    if zero == Some(*pos) {
+                        return false;
+                    }
+                    zero = Some(*pos);
+                }
+
+                IRInstruction::BeginProgram => {
+                    zero = Some(0);
+                }
+
+                IRInstruction::TraverseBy {
+                    val,
+                } => {
+                    zero = zero.map(|zero| zero - val);
+                }
+
+                IRInstruction::MutateValue {
+                    pos, ..
+                } => {
+                    zero = zero.filter(|zero| zero != pos);
+                }
+
+                IRInstruction::SetTo {
+                    pos,
+                    val,
+                } => {
+                    if *val == 0 {
+                        zero = Some(*pos);
+                    } else {
+                        zero = zero.filter(|zero| zero != pos);
+                    }
+                }
+
+                IRInstruction::ReadBytes {
+                    poslst,
+                } => {
+                    zero = zero.filter(|zero| !poslst.contains(zero));
+                }
+
+                IRInstruction::OutputBytes {
+                    ..
+                }
+                | IRInstruction::OutputLiteral {
+                    ..
+                }
+                | IRInstruction::Debug => {}
+
+                _ => {
+                    zero = None;
+                }
+            }
+
+            true
+        });
+    }
+
+    /// Removes `MutateValue`s and `SetTo`s whose cell is written again
+    /// before anything reads it, by going through the block backwards.
+    ///
+    /// Unlike reordering, this sees through I/O and pointer movement, but any
+    /// `ConditionalBlock` reads every cell as far as it's concerned. Cells
+    /// are never dead at the end of the block, since whatever comes after it
+    /// may read them.
+    fn remove_dead_stores(&mut self)
+    {
+        let mut buffer: Vec<IRInstruction> = Vec::new();
+
+        // Positions relative to the pointer at the current instruction,
+        // offset by `shift`, that are written before they are read
+        let mut dead = HashSet::<isize>::new();
+        let mut shift = 0_isize;
+
+        for instr in self.content.drain(..).rev() {
+            match &instr {
+                IRInstruction::TraverseBy {
+                    val,
+                } => {
+                    shift -= val;
+                }
+
+                IRInstruction::MutateValue {
+                    pos, ..
+                } => {
+                    // A mutation only reads the cell it writes
+                    if dead.contains(&(pos + shift)) {
+                        continue;
+                    }
+                }
+
+                IRInstruction::SetTo {
+                    pos, ..
+                } => {
+                    if !dead.insert(pos + shift) {
+                        continue;
+                    }
+                }
+
+                // Reading at the end of input may leave the cell as it was,
+                // so it counts as reading the cell rather than writing it
+                IRInstruction::OutputBytes {
+                    poslst,
+                }
+                | IRInstruction::ReadBytes {
+                    poslst,
+                } => {
+                    for pos in poslst {
+                        dead.remove(&(pos + shift));
+                    }
+                }
+
+                IRInstruction::OutputLiteral {
+                    ..
+                } => {}
+
+                _ => {
+                    dead.clear();
+                }
+            }
+
+            buffer.push(instr);
+        }
+
+        buffer.reverse();
+        self.content = buffer;
+    }
+}
@@ -0,0 +1,221 @@
+use super::TAPE_SIZE;
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+/// How many instructions and loop iterations are evaluated at most, so that
+/// long-running programs don't stall the optimizer.
+const MAX_STEPS: usize = 1_000_000;
+
+pub trait PartialEvaluation
+{
+    fn evaluate_prefix(&mut self);
+}
+
+/// Why evaluation had to stop.
+struct Stop;
+
+/// Runs instructions against a tape that is known exactly.
+///
+/// Cells hold their values as plain integers, without wrapping them to any
+/// width, so that the result holds whatever width the cells are. Loops on a
+/// value that is zero only for some widths stop the evaluation.
+struct Machine
+{
+    tape:   Vec<isize>,
+    ptr:    isize,
+    output: Vec<u8>,
+    steps:  usize,
+}
+
+impl Machine
+{
+    const fn step(&mut self) -> Result<(), Stop>
+    {
+        self.steps += 1;
+        if self.steps > MAX_STEPS {
+            return Err(Stop);
+        }
+        Ok(())
+    }
+
+    fn cell(&mut self, pos: isize) -> Result<&mut isize, Stop>
+    {
+        let index = self
+            .ptr
+            .checked_add(pos)
+            .and_then(|index| usize::try_from(index).ok())
+            .filter(|&index| index < TAPE_SIZE)
+            .ok_or(Stop)?;
+
+        if index >= self.tape.len() {
+            self.tape.resize(index + 1, 0);
+        }
+        self.tape.get_mut(index).ok_or(Stop)
+    }
+
+    /// Whether the cell at `pos` is non-zero, for loop conditions.
+    fn non_zero(&mut self, pos: isize) -> Result<bool, Stop>
+    {
+        let value = *self.cell(pos)?;
+        match value {
+            0 => Ok(false),
+            // Zero in some widths but not in others
+            _ if value % 256 == 0 => Err(Stop),
+            _ => Ok(true),
+        }
+    }
+
+    fn run(&mut self, block: &[IRInstruction]) -> Result<(), Stop>
+    {
+        for instr in block {
+            self.run_instruction(instr)?;
+        }
+        Ok(())
+    }
+
+    fn run_instruction(&mut self, instr: &IRInstruction) -> Result<(), Stop>
+    {
+        self.step()?;
+
+        match instr {
+            IRInstruction::BeginProgram => {}
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                self.ptr = self.ptr.checked_add(*val).ok_or(Stop)?;
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                let cell = self.cell(*pos)?;
+                *cell = cell.checked_add(*val).ok_or(Stop)?;
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                *self.cell(*pos)? = *val;
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    let byte = u8::try_from(self.cell(*pos)?.rem_euclid(256)).map_err(|_| Stop)?;
+                    self.output.push(byte);
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                self.output.extend(bytes);
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                while self.non_zero(*pos)? {
+                    self.step()?;
+                    self.run(block)?;
+                }
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                if self.non_zero(*pos)? {
+                    self.run(block)?;
+                }
+            }
+
+            // Input isn't known, and the debugger has to see the tape as the
+            // program left it
+            _ => {
+                return Err(Stop);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEvaluation for IRBlock
+{
+    /// Evaluates the start of a program, up to the first instruction whose
+    /// effects can't be known before running it, such as reading input.
+    ///
+    /// Programs start on a tape of zeros, so everything up to there is
+    /// replaced by the output it produced as a single `OutputLiteral`,
+    /// followed by `SetTo`s for every cell it left non-zero and the pointer
+    /// movement. Loops are evaluated whole or not at all, and only blocks
+    /// starting with `BeginProgram` are evaluated.
+    fn evaluate_prefix(&mut self)
+    {
+        let Some((IRInstruction::BeginProgram, rest)) = self.content.split_first() else {
+            return;
+        };
+
+        let mut machine = Machine {
+            tape:   Vec::new(),
+            ptr:    0,
+            output: Vec::new(),
+            steps:  0,
+        };
+
+        let mut evaluated = 0;
+        for instr in rest {
+            // Anything but a loop stops before changing a cell, apart from
+            // growing the tape with zeros, so only loops need a snapshot
+            let tape = matches!(
+                instr,
+                IRInstruction::ConditionalBlock(..) | IRInstruction::IfNonZero(..)
+            )
+            .then(|| machine.tape.clone());
+            let (ptr, output_len) = (machine.ptr, machine.output.len());
+
+            if machine.run_instruction(instr).is_err() {
+                if let Some(tape) = tape {
+                    machine.tape = tape;
+                }
+                machine.ptr = ptr;
+                machine.output.truncate(output_len);
+                break;
+            }
+
+            evaluated += 1;
+        }
+
+        if evaluated == 0 {
+            return;
+        }
+
+        let mut buffer = vec![IRInstruction::BeginProgram];
+
+        if !machine.output.is_empty() {
+            buffer.push(IRInstruction::OutputLiteral {
+                bytes: machine.output
+            });
+        }
+
+        buffer.extend(
+            (0_isize..)
+                .zip(machine.tape)
+                .filter(|&(_, val)| val != 0)
+                .map(|(pos, val)| IRInstruction::SetTo {
+                    pos,
+                    val,
+                }),
+        );
+
+        if machine.ptr != 0 {
+            buffer.push(IRInstruction::TraverseBy {
+                val: machine.ptr
+            });
+        }
+
+        buffer.extend(rest.iter().skip(evaluated).cloned());
+
+        self.content = buffer;
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use super::TAPE_SIZE;
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+/// The values of cells known at some point of a block.
+///
+/// Values are plain integers, without wrapping them to any width, so a cell
+/// is only known to be zero when it holds exactly zero.
+pub struct Knowledge
+{
+    /// Cells whose values are known, or known not to be known, by their
+    /// position relative to where the pointer was when the positions were
+    /// last reset.
+    cells:  HashMap<isize, Option<isize>>,
+    shift:  isize,
+    /// Whether every cell not in `cells` is zero, which only holds from the
+    /// start of a program, where positions are relative to the first cell.
+    zeroed: bool,
+}
+
+impl Knowledge
+{
+    pub fn unknown() -> Self
+    {
+        Self {
+            cells:  HashMap::new(),
+            shift:  0,
+            zeroed: false,
+        }
+    }
+
+    /// The value of the cell at `pos`, relative to the pointer.
+    pub fn get(&self, pos: isize) -> Option<isize>
+    {
+        let pos = pos + self.shift;
+        self.cells.get(&pos).map_or_else(
+            || {
+                usize::try_from(pos)
+                    .is_ok_and(|index| self.zeroed && index < TAPE_SIZE)
+                    .then_some(0)
+            },
+            |val| *val,
+        )
+    }
+
+    /// The known cell closest to `pos`, along with its value, both relative
+    /// to the pointer.
+    pub fn nearest_known(&self, pos: isize) -> Option<(isize, isize)>
+    {
+        let listed = self
+            .cells
+            .iter()
+            .filter_map(|(&cell, &val)| Some((cell - self.shift, val?)));
+
+        // Only so many cells can be listed, so on a zeroed tape one of the
+        // others is found within as many steps
+        let unlisted = if self.zeroed {
+            (0..=self.cells.len())
+                .filter_map(|distance| isize::try_from(distance).ok())
+                .flat_map(|distance| [pos - distance, pos + distance])
+                .find_map(|cell| Some((cell, self.get(cell)?)))
+        } else {
+            None
+        };
+
+        listed.chain(unlisted).min_by_key(|(cell, _)| cell.abs_diff(pos))
+    }
+
+    fn set(&mut self, pos: isize, val: Option<isize>)
+    {
+        let pos = pos + self.shift;
+
+        // Cells that aren't listed are already unknown, unless the tape is
+        // known to be zeroed
+        if val.is_some() || self.zeroed {
+            self.cells.insert(pos, val);
+        } else {
+            self.cells.remove(&pos);
+        }
+    }
+
+    /// Follows the cells through running `instr`, starting from a tape of
+    /// zeros at `BeginProgram`.
+    ///
+    /// Loops leave the cell they're on at zero, and the cells they never
+    /// write as they were if they end where they started. Otherwise nothing
+    /// is known past them.
+    pub fn update(&mut self, instr: &IRInstruction)
+    {
+        match instr {
+            IRInstruction::BeginProgram => {
+                *self = Self {
+                    zeroed: true,
+                    ..Self::unknown()
+                };
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                self.shift += val;
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                if let Some(known) = self.get(*pos) {
+                    self.set(*pos, Some(known + val));
+                }
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                self.set(*pos, Some(*val));
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    self.set(*pos, None);
+                }
+            }
+
+            IRInstruction::ConditionalBlock(cond, body) | IRInstruction::IfNonZero(cond, body) => {
+                if self.get(*cond) == Some(0) {
+                    return;
+                }
+
+                if let Some(written) = written_cells(body) {
+                    for pos in written {
+                        self.set(pos, None);
+                    }
+                } else {
+                    *self = Self::unknown();
+                }
+
+                // Loops only ever end on a zero cell
+                self.set(*cond, Some(0));
+            }
+
+            IRInstruction::EndProgram
+            | IRInstruction::OutputBytes {
+                ..
+            }
+            | IRInstruction::OutputLiteral {
+                ..
+            }
+            | IRInstruction::Debug => {}
+        }
+    }
+}
+
+/// Returns every position a block writes to, relative to the pointer at its
+/// start, or `None` if it or any loop inside of it doesn't end where it
+/// started, which makes the positions depend on how often it runs.
+fn written_cells(block: &IRBlock) -> Option<Vec<isize>>
+{
+    let mut written = Vec::new();
+    let mut shift = 0_isize;
+
+    for instr in block.iter() {
+        match instr {
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                shift += val;
+            }
+
+            IRInstruction::MutateValue {
+                pos, ..
+            }
+            | IRInstruction::SetTo {
+                pos, ..
+            } => {
+                written.push(pos + shift);
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                written.extend(poslst.iter().map(|pos| pos + shift));
+            }
+
+            IRInstruction::ConditionalBlock(_, block) | IRInstruction::IfNonZero(_, block) => {
+                written.extend(written_cells(block)?.into_iter().map(|pos| pos + shift));
+            }
+
+            _ => {}
+        }
+    }
+
+    (shift == 0).then_some(written)
+}
@@ -81,6 +81,25 @@ impl MergeInstructions for IRBlock
                     continue 'a;
                 }
 
+                IRInstruction::OutputLiteral {
+                    bytes: lbytes,
+                } => {
+                    let mut lbytes = lbytes.clone();
+                    while let Some(IRInstruction::OutputLiteral {
+                        bytes: rbytes,
+                    }) = pk.peek()
+                    {
+                        lbytes.extend(rbytes);
+                        pk.next();
+                    }
+
+                    buffer.push(IRInstruction::OutputLiteral {
+                        bytes: lbytes
+                    });
+
+                    continue 'a;
+                }
+
                 _ => {}
             }
 
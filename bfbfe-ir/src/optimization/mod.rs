@@ -1,3 +1,13 @@
+pub mod conditional;
 pub mod constants;
+pub mod dead;
+pub mod evaluate;
+pub mod knowledge;
 pub mod merge;
+pub mod propagate;
 pub mod reorder;
+
+/// The amount of cells every backend allocates for the tape. Passes that know
+/// the tape starts out zeroed only rely on it within this range, leaving
+/// cells past it for the program to fail on when it runs.
+const TAPE_SIZE: usize = 30000;
@@ -0,0 +1,144 @@
+use super::knowledge::Knowledge;
+use crate::block::IRBlock;
+use crate::instruction::IRInstruction;
+
+/// Loops are only unrolled into at most this many instructions.
+const MAX_UNROLLED: usize = 64;
+
+pub trait ConstantPropagation
+{
+    fn propagate_constants(&mut self);
+}
+
+/// Returns how often a loop with the given body on the cell at `cond` runs
+/// when that cell starts at `counter`, if the body is straight-line code that
+/// ends where it started and steps the counter by the same amount every time.
+fn iterations(body: &IRBlock, cond: isize, counter: isize) -> Option<usize>
+{
+    let mut step = 0_isize;
+    let mut shift = 0_isize;
+
+    for instr in body.iter() {
+        match instr {
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                shift += val;
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                if pos + shift == cond {
+                    step += val;
+                }
+            }
+
+            IRInstruction::SetTo {
+                pos, ..
+            } => {
+                if pos + shift == cond {
+                    return None;
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                if poslst.iter().any(|pos| pos + shift == cond) {
+                    return None;
+                }
+            }
+
+            IRInstruction::OutputBytes {
+                ..
+            }
+            | IRInstruction::OutputLiteral {
+                ..
+            } => {}
+
+            _ => {
+                return None;
+            }
+        }
+    }
+
+    // The counter has to reach zero exactly without passing another multiple
+    // of 256, for it to run as often with any width
+    if shift != 0 || step == 0 || counter % step != 0 || counter.unsigned_abs() >= 256 {
+        return None;
+    }
+    usize::try_from(-counter / step)
+        .ok()
+        .filter(|&iterations| iterations > 0)
+}
+
+impl ConstantPropagation for IRBlock
+{
+    /// Follows the values of cells through the block, starting from a tape
+    /// of zeros for a whole program and from nothing known otherwise.
+    ///
+    /// `MutateValue`s of known cells become `SetTo`s, loops on a cell known
+    /// to be zero are removed, and loops entered with a known counter are
+    /// unrolled when they are short enough. `IfNonZero`s on a cell known not
+    /// to be zero are always inlined.
+    fn propagate_constants(&mut self)
+    {
+        let mut buffer: Vec<IRInstruction> = Vec::new();
+        let mut knowledge = Knowledge::unknown();
+
+        // Unrolled loop bodies are pushed back onto this, so that they are
+        // propagated through as well
+        let mut pending = self.content.drain(..).rev().collect::<Vec<_>>();
+
+        while let Some(instr) = pending.pop() {
+            match &instr {
+                IRInstruction::MutateValue {
+                    pos,
+                    val,
+                } => {
+                    if let Some(known) = knowledge.get(*pos) {
+                        knowledge.update(&instr);
+                        buffer.push(IRInstruction::SetTo {
+                            pos: *pos,
+                            val: known + val,
+                        });
+                        continue;
+                    }
+                }
+
+                IRInstruction::ConditionalBlock(cond, body) | IRInstruction::IfNonZero(cond, body) => {
+                    match knowledge.get(*cond) {
+                        Some(0) => continue,
+
+                        Some(counter) => {
+                            let unrolled = match &instr {
+                                // Non-zero whatever the width of the cells
+                                IRInstruction::IfNonZero(..) => (counter % 256 != 0).then_some(1),
+                                _ => iterations(body, *cond, counter)
+                                    .filter(|iterations| iterations.saturating_mul(body.len()) <= MAX_UNROLLED),
+                            };
+
+                            if let Some(iterations) = unrolled {
+                                for _ in 0..iterations {
+                                    pending.extend(body.iter().rev().cloned());
+                                }
+                                continue;
+                            }
+                        }
+
+                        None => {}
+                    }
+                }
+
+                _ => {}
+            }
+
+            knowledge.update(&instr);
+            buffer.push(instr);
+        }
+
+        self.content = buffer;
+    }
+}
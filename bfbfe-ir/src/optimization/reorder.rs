@@ -75,14 +75,43 @@ impl ReorderingOptimizations for IRBlock
                     pos,
                     val,
                 } => {
-                    mutate_moves.push((pos + ptr_shift, *val));
+                    let pos = pos + ptr_shift;
+
+                    // Mutating a cell that was set changes what it is set to
+                    match set_moves.iter_mut().chain(&mut mutate_moves).find(|(p, _)| *p == pos) {
+                        Some((_, v)) => *v += val,
+                        None => mutate_moves.push((pos, *val)),
+                    }
                 }
 
                 IRInstruction::SetTo {
                     pos,
                     val,
                 } => {
-                    set_moves.push((pos + ptr_shift, *val));
+                    let pos = pos + ptr_shift;
+
+                    // Setting a cell overrides everything done to it before
+                    set_moves.retain(|(p, _)| *p != pos);
+                    mutate_moves.retain(|(p, _)| *p != pos);
+                    set_moves.push((pos, *val));
+                }
+
+                // A loop that ends where it started leaves the pointer where
+                // it was, so it can be moved after it instead, with the loop
+                // accessing its cells at an offset
+                IRInstruction::ConditionalBlock(_, block) | IRInstruction::IfNonZero(_, block)
+                    if ptr_shift != 0 && block.is_balanced() =>
+                {
+                    cleanup(&mut new_block, &mut set_moves, &mut mutate_moves, &mut 0);
+
+                    let mut sunk = instr.clone();
+                    if let IRInstruction::ConditionalBlock(pos, block) | IRInstruction::IfNonZero(pos, block) =
+                        &mut sunk
+                    {
+                        *pos += ptr_shift;
+                        block.offset_by(ptr_shift);
+                    }
+                    new_block.push(sunk);
                 }
 
                 _ => {
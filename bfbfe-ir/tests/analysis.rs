@@ -0,0 +1,58 @@
+mod common;
+
+use bfbfe_ir::analysis::Footprint;
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn footprints_bound_what_blocks_do(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let footprint = block.footprint();
+
+        if let Some((cells, ptr)) = common::reach(&block, &input) {
+            prop_assert!(footprint.min_stride.is_none_or(|min| min <= ptr), "{:?} ends at {}", footprint, ptr);
+            prop_assert!(footprint.max_stride.is_none_or(|max| ptr <= max), "{:?} ends at {}", footprint, ptr);
+
+            for cell in cells {
+                prop_assert!(footprint.min_offset.is_none_or(|min| min <= cell), "{:?} reaches {}", footprint, cell);
+                prop_assert!(footprint.max_offset.is_none_or(|max| cell <= max), "{:?} reaches {}", footprint, cell);
+            }
+        }
+    }
+}
+
+/// `[->>+<]` moves right by one cell every iteration, so it can reach any
+/// cell to the right but none to the left.
+#[test]
+fn footprints_of_unbalanced_loops_are_open_in_their_direction()
+{
+    let body = IRBlock::with_instructions(vec![
+        IRInstruction::MutateValue {
+            pos: 0, val: -1
+        },
+        IRInstruction::MutateValue {
+            pos: 2, val: 1
+        },
+        IRInstruction::TraverseBy {
+            val: 1
+        },
+    ]);
+    assert_eq!(body.footprint().stride(), Some(1));
+    assert_eq!(body.footprint().cells(), Some(0..3));
+
+    let block = IRBlock::with_instructions(vec![IRInstruction::ConditionalBlock(0, Box::new(body))]);
+    assert_eq!(
+        block.footprint(),
+        Footprint {
+            min_stride: Some(0),
+            max_stride: None,
+            min_offset: Some(0),
+            max_offset: None,
+        }
+    );
+    assert!(!block.is_balanced());
+    assert_eq!(block.footprint().cells(), None);
+}
@@ -0,0 +1,280 @@
+#![allow(dead_code, clippy::std_instead_of_core, clippy::std_instead_of_alloc)]
+
+//! Generates arbitrary IR and runs it with a reference interpreter, so that
+//! optimizations can be checked against the unoptimized program.
+
+use std::collections::BTreeMap;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Keeps programs that never finish from stalling the tests.
+const MAX_STEPS: usize = 10_000;
+
+/// What a program did: its output, every non-zero cell of the tape and the
+/// final pointer.
+pub type Outcome = (Vec<u8>, BTreeMap<isize, u32>, isize);
+
+/// What reading past the end of input stores, the same as the runtime's
+/// `EofPolicy`.
+#[derive(Clone, Copy, Debug)]
+pub enum Eof
+{
+    Max,
+    Zero,
+    Unchanged,
+}
+
+/// Every EOF behavior along with every cell width the runtime supports, as
+/// the mask of a cell, none of which optimizations may rely on.
+pub const CONFIGS: [(Eof, u32); 9] = [
+    (Eof::Max, 0xFF),
+    (Eof::Zero, 0xFF),
+    (Eof::Unchanged, 0xFF),
+    (Eof::Max, 0xFFFF),
+    (Eof::Zero, 0xFFFF),
+    (Eof::Unchanged, 0xFFFF),
+    (Eof::Max, u32::MAX),
+    (Eof::Zero, u32::MAX),
+    (Eof::Unchanged, u32::MAX),
+];
+
+fn offset() -> impl Strategy<Value = isize>
+{
+    -2_isize..=2
+}
+
+fn value() -> impl Strategy<Value = isize>
+{
+    prop_oneof![-8_isize..=8, -300_isize..=300]
+}
+
+fn leaf() -> impl Strategy<Value = IRInstruction>
+{
+    prop_oneof![
+        offset().prop_map(|val| IRInstruction::TraverseBy {
+            val
+        }),
+        (offset(), value()).prop_map(|(pos, val)| IRInstruction::MutateValue {
+            pos,
+            val
+        }),
+        (offset(), value()).prop_map(|(pos, val)| IRInstruction::SetTo {
+            pos,
+            val
+        }),
+        vec(offset(), 1..3).prop_map(|poslst| IRInstruction::OutputBytes {
+            poslst
+        }),
+        vec(offset(), 1..2).prop_map(|poslst| IRInstruction::ReadBytes {
+            poslst
+        }),
+        vec(any::<u8>(), 1..3).prop_map(|bytes| IRInstruction::OutputLiteral {
+            bytes
+        }),
+    ]
+}
+
+/// Any instruction, with loops nested a few levels deep. Loops are kept rare,
+/// since they are skipped whenever they are reached on a zero cell, and are
+/// mostly on the current cell. `IfNonZero`s clear the cell they are on, as
+/// they have to.
+pub fn instruction() -> impl Strategy<Value = IRInstruction>
+{
+    leaf().prop_recursive(3, 32, 6, |inner| {
+        let cond = prop_oneof![3 => Just(0_isize), 1 => offset()];
+        let body = vec(prop_oneof![3 => leaf(), 1 => inner], 0..6);
+        prop_oneof![
+            3 => (cond.clone(), body.clone()).prop_map(|(cond, content)| {
+                IRInstruction::ConditionalBlock(cond, Box::new(IRBlock::with_instructions(content)))
+            }),
+            1 => (cond, body).prop_map(|(cond, mut content)| {
+                content.push(IRInstruction::SetTo {
+                    pos: cond,
+                    val: 0,
+                });
+                IRInstruction::IfNonZero(cond, Box::new(IRBlock::with_instructions(content)))
+            }),
+        ]
+    })
+}
+
+pub fn block() -> impl Strategy<Value = IRBlock>
+{
+    vec(prop_oneof![3 => leaf(), 1 => instruction()], 0..16).prop_map(IRBlock::with_instructions)
+}
+
+/// A whole program, which starts from a tape of zeros.
+pub fn program() -> impl Strategy<Value = IRBlock>
+{
+    block().prop_map(|mut block| {
+        block.insert(0, IRInstruction::BeginProgram);
+        block.push(IRInstruction::EndProgram);
+        block
+    })
+}
+
+struct Machine<'a>
+{
+    tape:   BTreeMap<isize, u32>,
+    ptr:    isize,
+    input:  &'a [u8],
+    output: Vec<u8>,
+    steps:  usize,
+    eof:    Eof,
+    mask:   u32,
+}
+
+impl<'a> Machine<'a>
+{
+    const fn new(input: &'a [u8], (eof, mask): (Eof, u32)) -> Self
+    {
+        Self {
+            tape: BTreeMap::new(),
+            ptr: 0,
+            input,
+            output: Vec::new(),
+            steps: 0,
+            eof,
+            mask,
+        }
+    }
+
+    fn cell(&mut self, pos: isize) -> &mut u32
+    {
+        self.tape.entry(self.ptr + pos).or_default()
+    }
+
+    fn wrap(&self, val: i64) -> u32
+    {
+        u32::try_from(val.rem_euclid(1 << 32)).unwrap_or_default() & self.mask
+    }
+
+    /// Returns `None` once the program runs out of steps.
+    fn run(&mut self, block: &IRBlock) -> Option<()>
+    {
+        for instr in block.iter() {
+            self.steps += 1;
+            if self.steps > MAX_STEPS {
+                return None;
+            }
+
+            match instr {
+                IRInstruction::TraverseBy {
+                    val,
+                } => self.ptr += val,
+
+                IRInstruction::MutateValue {
+                    pos,
+                    val,
+                } => {
+                    let val = i64::from(*self.cell(*pos)) + to_i64(*val);
+                    *self.cell(*pos) = self.wrap(val);
+                }
+
+                IRInstruction::SetTo {
+                    pos,
+                    val,
+                } => *self.cell(*pos) = self.wrap(to_i64(*val)),
+
+                IRInstruction::OutputBytes {
+                    poslst,
+                } => {
+                    for pos in poslst {
+                        let [.., byte] = self.cell(*pos).to_be_bytes();
+                        self.output.push(byte);
+                    }
+                }
+
+                IRInstruction::OutputLiteral {
+                    bytes,
+                } => self.output.extend(bytes),
+
+                IRInstruction::ReadBytes {
+                    poslst,
+                } => {
+                    for pos in poslst {
+                        let byte = self.input.first().copied();
+                        self.input = self.input.get(1..).unwrap_or_default();
+                        *self.cell(*pos) = match (byte, self.eof) {
+                            (Some(byte), _) => u32::from(byte),
+                            (None, Eof::Max) => self.mask,
+                            (None, Eof::Zero) => 0,
+                            (None, Eof::Unchanged) => *self.cell(*pos),
+                        };
+                    }
+                }
+
+                IRInstruction::ConditionalBlock(pos, body) => {
+                    while *self.cell(*pos) != 0 {
+                        // Counted on its own, since the body may be empty
+                        self.steps += 1;
+                        if self.steps > MAX_STEPS {
+                            return None;
+                        }
+                        self.run(body)?;
+                    }
+                }
+
+                IRInstruction::IfNonZero(pos, body) if *self.cell(*pos) != 0 => self.run(body)?,
+
+                _ => {}
+            }
+        }
+
+        Some(())
+    }
+}
+
+fn to_i64(val: isize) -> i64
+{
+    i64::try_from(val).unwrap_or_default()
+}
+
+/// Runs `block` against an unbounded tape of bytes, with the end of input
+/// reading as 255 like in the C backend, returning `None` if it doesn't
+/// finish in time.
+pub fn run(block: &IRBlock, input: &[u8]) -> Option<Outcome>
+{
+    run_with(block, input, (Eof::Max, 0xFF))
+}
+
+/// Runs `block` like `run`, with the given EOF behavior and mask of a cell.
+pub fn run_with(block: &IRBlock, input: &[u8], config: (Eof, u32)) -> Option<Outcome>
+{
+    let mut machine = Machine::new(input, config);
+    machine.run(block)?;
+
+    machine.tape.retain(|_, cell| *cell != 0);
+    Some((machine.output, machine.tape, machine.ptr))
+}
+
+/// Runs `block` like `run`, returning every cell it accessed and the final
+/// pointer instead.
+pub fn reach(block: &IRBlock, input: &[u8]) -> Option<(Vec<isize>, isize)>
+{
+    let mut machine = Machine::new(input, (Eof::Max, 0xFF));
+    machine.run(block)?;
+
+    Some((machine.tape.into_keys().collect(), machine.ptr))
+}
+
+/// Checks that `optimized` does the same as `original` under every EOF
+/// behavior and cell width, as long as `original` finishes at all.
+pub fn assert_equivalent(original: &IRBlock, optimized: &IRBlock, input: &[u8]) -> Result<(), TestCaseError>
+{
+    for config in CONFIGS {
+        if let Some(expected) = run_with(original, input, config) {
+            prop_assert_eq!(
+                Some(expected),
+                run_with(optimized, input, config),
+                "optimized into {:?} with {:?}",
+                optimized,
+                config
+            );
+        }
+    }
+    Ok(())
+}
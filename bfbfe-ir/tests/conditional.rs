@@ -0,0 +1,73 @@
+mod common;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_ir::optimization::conditional::ConditionalOptimization;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn finding_if_non_zero_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.find_if_non_zero();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    #[test]
+    fn finding_if_non_zero_is_idempotent(mut block in common::block())
+    {
+        block.find_if_non_zero();
+        let mut again = block.clone();
+        again.find_if_non_zero();
+        prop_assert_eq!(block, again);
+    }
+}
+
+#[test]
+fn finding_if_non_zero_needs_a_zero_cell()
+{
+    let body = |content| Box::new(IRBlock::with_instructions(content));
+    let clear = IRInstruction::SetTo {
+        pos: 0, val: 0
+    };
+    let right = IRInstruction::TraverseBy {
+        val: 1
+    };
+
+    let mut block = IRBlock::with_instructions(vec![
+        // `[[-]]`, and `[>[-]]` which ends on the cleared cell
+        IRInstruction::ConditionalBlock(0, body(vec![clear.clone()])),
+        IRInstruction::ConditionalBlock(0, body(vec![right.clone(), clear.clone()])),
+        // `[[-]>]` ends on another cell
+        IRInstruction::ConditionalBlock(0, body(vec![clear.clone(), right.clone()])),
+        // Setting the cell to 256 only clears it for bytes
+        IRInstruction::ConditionalBlock(
+            0,
+            body(vec![IRInstruction::SetTo {
+                pos: 0, val: 256
+            }]),
+        ),
+        // `[>[<]]` ends on the cell a loop ended on
+        IRInstruction::ConditionalBlock(
+            0,
+            body(vec![
+                right.clone(),
+                IRInstruction::ConditionalBlock(
+                    0,
+                    body(vec![IRInstruction::TraverseBy {
+                        val: -1
+                    }]),
+                ),
+            ]),
+        ),
+    ]);
+    block.find_if_non_zero();
+
+    let kinds = block
+        .iter()
+        .map(|instr| matches!(instr, IRInstruction::IfNonZero(..)))
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, [true, true, false, false, true]);
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_ir::optimization::constants::ConstantsOptimization;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn finding_set_to_zero_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.find_set_to_zero();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    /// Loops of a single mutation either reach zero within 256 iterations or
+    /// never do, so the step limit tells them apart exactly. The ones that
+    /// never finish have to be kept.
+    #[test]
+    fn finding_set_to_zero_keeps_infinite_loops(cell in any::<u8>(), val in -300_isize..=300)
+    {
+        let block = IRBlock::with_instructions(vec![
+            IRInstruction::MutateValue {
+                pos: 0,
+                val: isize::from(cell),
+            },
+            IRInstruction::ConditionalBlock(0, Box::new(IRBlock::with_instructions(vec![IRInstruction::MutateValue {
+                pos: 0,
+                val,
+            }]))),
+        ]);
+
+        let mut optimized = block.clone();
+        optimized.find_set_to_zero();
+        prop_assert_eq!(common::run(&block, &[]).is_some(), common::run(&optimized, &[]).is_some());
+    }
+
+    #[test]
+    fn finding_set_to_zero_is_idempotent(mut block in common::block())
+    {
+        block.find_set_to_zero();
+        let mut again = block.clone();
+        again.find_set_to_zero();
+        prop_assert_eq!(block, again);
+    }
+
+    #[test]
+    fn finding_set_to_value_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.find_set_to_value();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    #[test]
+    fn finding_set_to_value_is_idempotent(mut block in common::block())
+    {
+        block.find_set_to_value();
+        let mut again = block.clone();
+        again.find_set_to_value();
+        prop_assert_eq!(block, again);
+    }
+
+    #[test]
+    fn finding_constant_output_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.find_constant_output();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    #[test]
+    fn finding_constant_output_is_idempotent(mut block in common::block())
+    {
+        block.find_constant_output();
+        let mut again = block.clone();
+        again.find_constant_output();
+        prop_assert_eq!(block, again);
+    }
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_ir::optimization::dead::DeadCodeElimination;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn removing_dead_loops_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.remove_dead_loops();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    #[test]
+    fn removing_dead_loops_from_programs_preserves_behavior(
+        program in common::program(),
+        input in vec(any::<u8>(), 0..4)
+    )
+    {
+        let mut optimized = program.clone();
+        optimized.remove_dead_loops();
+        common::assert_equivalent(&program, &optimized, &input)?;
+    }
+
+    #[test]
+    fn removing_dead_loops_is_idempotent(mut program in common::program())
+    {
+        program.remove_dead_loops();
+        let mut again = program.clone();
+        again.remove_dead_loops();
+        prop_assert_eq!(program, again);
+    }
+
+    #[test]
+    fn removing_dead_stores_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut optimized = block.clone();
+        optimized.remove_dead_stores();
+        common::assert_equivalent(&block, &optimized, &input)?;
+    }
+
+    #[test]
+    fn removing_dead_stores_is_idempotent(mut block in common::block())
+    {
+        block.remove_dead_stores();
+        let mut again = block.clone();
+        again.remove_dead_stores();
+        prop_assert_eq!(block, again);
+    }
+
+    /// The loop at the start of a program and every loop right after another
+    /// loop never run, whatever they contain.
+    #[test]
+    fn removing_dead_loops_removes_comments(
+        comment in common::instruction(),
+        body in common::block(),
+        dead in common::block()
+    )
+    {
+        let mut program = IRBlock::with_instructions(vec![
+            IRInstruction::BeginProgram,
+            IRInstruction::ConditionalBlock(0, Box::new(IRBlock::with_instructions(vec![comment]))),
+            IRInstruction::MutateValue {
+                pos: 0,
+                val: 1,
+            },
+            IRInstruction::ConditionalBlock(0, Box::new(body.clone())),
+            IRInstruction::ConditionalBlock(0, Box::new(dead)),
+            IRInstruction::EndProgram,
+        ]);
+        program.remove_dead_loops();
+
+        prop_assert_eq!(
+            program,
+            IRBlock::with_instructions(vec![
+                IRInstruction::BeginProgram,
+                IRInstruction::MutateValue {
+                    pos: 0,
+                    val: 1,
+                },
+                IRInstruction::ConditionalBlock(0, Box::new(body)),
+                IRInstruction::EndProgram,
+            ])
+        );
+    }
+}
+
+#[test]
+fn removing_dead_stores_sees_through_io()
+{
+    let mut block = IRBlock::with_instructions(vec![
+        IRInstruction::SetTo {
+            pos: 1, val: 3
+        },
+        IRInstruction::MutateValue {
+            pos: 0, val: 2
+        },
+        IRInstruction::MutateValue {
+            pos: 2, val: 7
+        },
+        IRInstruction::OutputBytes {
+            poslst: vec![1]
+        },
+        IRInstruction::TraverseBy {
+            val: 1
+        },
+        IRInstruction::OutputLiteral {
+            bytes: b"x".to_vec()
+        },
+        IRInstruction::ReadBytes {
+            poslst: vec![-1]
+        },
+        IRInstruction::SetTo {
+            pos: 1, val: 5
+        },
+    ]);
+    block.remove_dead_stores();
+
+    // Only the last mutation is overwritten before anything reads it. The
+    // first store is output, and reading input into the cell of the other
+    // mutation may leave it as it was at the end of input
+    assert_eq!(
+        block,
+        IRBlock::with_instructions(vec![
+            IRInstruction::SetTo {
+                pos: 1, val: 3
+            },
+            IRInstruction::MutateValue {
+                pos: 0, val: 2
+            },
+            IRInstruction::OutputBytes {
+                poslst: vec![1]
+            },
+            IRInstruction::TraverseBy {
+                val: 1
+            },
+            IRInstruction::OutputLiteral {
+                bytes: b"x".to_vec()
+            },
+            IRInstruction::ReadBytes {
+                poslst: vec![-1]
+            },
+            IRInstruction::SetTo {
+                pos: 1, val: 5
+            },
+        ])
+    );
+}
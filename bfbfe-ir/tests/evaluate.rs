@@ -0,0 +1,24 @@
+mod common;
+
+use bfbfe_ir::optimization::evaluate::PartialEvaluation;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn evaluating_preserves_behavior(program in common::program(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut evaluated = program.clone();
+        evaluated.evaluate_prefix();
+        common::assert_equivalent(&program, &evaluated, &input)?;
+    }
+
+    #[test]
+    fn evaluating_is_idempotent(mut program in common::program())
+    {
+        program.evaluate_prefix();
+        let mut again = program.clone();
+        again.evaluate_prefix();
+        prop_assert_eq!(program, again);
+    }
+}
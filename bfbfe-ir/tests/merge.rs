@@ -0,0 +1,24 @@
+mod common;
+
+use bfbfe_ir::optimization::merge::MergeInstructions;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn merging_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut merged = block.clone();
+        merged.merge_instructions();
+        common::assert_equivalent(&block, &merged, &input)?;
+    }
+
+    #[test]
+    fn merging_is_idempotent(mut block in common::block())
+    {
+        block.merge_instructions();
+        let mut again = block.clone();
+        again.merge_instructions();
+        prop_assert_eq!(block, again);
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use bfbfe_ir::optimization::propagate::ConstantPropagation;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn propagating_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut propagated = block.clone();
+        propagated.propagate_constants();
+        common::assert_equivalent(&block, &propagated, &input)?;
+    }
+
+    #[test]
+    fn propagating_through_programs_preserves_behavior(
+        program in common::program(),
+        input in vec(any::<u8>(), 0..4)
+    )
+    {
+        let mut propagated = program.clone();
+        propagated.propagate_constants();
+        common::assert_equivalent(&program, &propagated, &input)?;
+    }
+
+    #[test]
+    fn propagating_is_idempotent(mut program in common::program())
+    {
+        program.propagate_constants();
+        let mut again = program.clone();
+        again.propagate_constants();
+        prop_assert_eq!(program, again);
+    }
+}
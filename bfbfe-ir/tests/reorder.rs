@@ -0,0 +1,68 @@
+mod common;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_ir::optimization::reorder::ReorderingOptimizations;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn reordering_preserves_behavior(block in common::block(), input in vec(any::<u8>(), 0..4))
+    {
+        let mut reordered = block.clone();
+        reordered.reorder_instructions();
+        common::assert_equivalent(&block, &reordered, &input)?;
+    }
+
+    #[test]
+    fn reordering_is_idempotent(mut block in common::block())
+    {
+        block.reorder_instructions();
+        let mut again = block.clone();
+        again.reorder_instructions();
+        prop_assert_eq!(block, again);
+    }
+}
+
+/// `>>>[->+<]<<<` moves the pointer neither before nor after the loop, which
+/// accesses its cells at an offset instead.
+#[test]
+fn reordering_sinks_offsets_into_balanced_loops()
+{
+    let mut block = IRBlock::with_instructions(vec![
+        IRInstruction::TraverseBy {
+            val: 3
+        },
+        IRInstruction::ConditionalBlock(
+            0,
+            Box::new(IRBlock::with_instructions(vec![
+                IRInstruction::MutateValue {
+                    pos: 0, val: -1
+                },
+                IRInstruction::MutateValue {
+                    pos: 1, val: 1
+                },
+            ])),
+        ),
+        IRInstruction::TraverseBy {
+            val: -3
+        },
+    ]);
+    block.reorder_instructions();
+
+    assert_eq!(
+        block,
+        IRBlock::with_instructions(vec![IRInstruction::ConditionalBlock(
+            3,
+            Box::new(IRBlock::with_instructions(vec![
+                IRInstruction::MutateValue {
+                    pos: 3, val: -1
+                },
+                IRInstruction::MutateValue {
+                    pos: 4, val: 1
+                },
+            ])),
+        )])
+    );
+}
@@ -111,13 +111,20 @@ fn _instructionize(tokens: &[Token]) -> Vec<IRInstruction>
                         }
                     }
 
-                    IRInstruction::ConditionalBlock(Box::new(IRBlock::with_instructions(_instructionize(&new_tokens))))
+                    IRInstruction::ConditionalBlock(
+                        0,
+                        Box::new(IRBlock::with_instructions(_instructionize(&new_tokens))),
+                    )
                 };
 
                 content.push(res);
             }
 
             Token::JumpBackward => {}
+
+            Token::Debug => {
+                content.push(IRInstruction::Debug);
+            }
         };
     }
 
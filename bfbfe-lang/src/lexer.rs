@@ -1,21 +1,68 @@
 use itertools::Itertools;
 
+use crate::location::Location;
 use crate::token::Token;
 
+/// Extensions to the eight Brainfuck commands. Every extension is disabled by
+/// default, leaving its characters to be comments.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Dialect
+{
+    /// Recognize `#` as [`Token::Debug`], which dumps the tape or breaks into
+    /// the debugger.
+    pub debug: bool,
+}
+
+const fn tokenize_char(ch: char, dialect: Dialect) -> Option<Token>
+{
+    match ch {
+        '>' => Some(Token::IncrementPointer),
+        '<' => Some(Token::DecrementPointer),
+        '+' => Some(Token::IncrementValue),
+        '-' => Some(Token::DecrementValue),
+        '.' => Some(Token::PushByte),
+        ',' => Some(Token::ReadByte),
+        '[' => Some(Token::JumpForward),
+        ']' => Some(Token::JumpBackward),
+        '#' if dialect.debug => Some(Token::Debug),
+        _ => None,
+    }
+}
+
 pub fn tokenize_whole_program(program: &[char]) -> Vec<Token>
+{
+    tokenize_dialect(program, Dialect::default())
+}
+
+pub fn tokenize_dialect(program: &[char], dialect: Dialect) -> Vec<Token>
 {
     program
         .iter()
-        .filter_map(|ch| match ch {
-            '>' => Some(Token::IncrementPointer),
-            '<' => Some(Token::DecrementPointer),
-            '+' => Some(Token::IncrementValue),
-            '-' => Some(Token::DecrementValue),
-            '.' => Some(Token::PushByte),
-            ',' => Some(Token::ReadByte),
-            '[' => Some(Token::JumpForward),
-            ']' => Some(Token::JumpBackward),
-            _ => None,
-        })
+        .filter_map(|&ch| tokenize_char(ch, dialect))
         .collect_vec()
 }
+
+/// Like [`tokenize_dialect`], but pairs every token with where it was found in
+/// the source.
+pub fn tokenize_with_locations(program: &[char], dialect: Dialect) -> Vec<(Token, Location)>
+{
+    let mut location = Location {
+        line: 1, column: 1
+    };
+
+    let mut tokens = Vec::new();
+    for &ch in program {
+        if let Some(token) = tokenize_char(ch, dialect) {
+            tokens.push((token, location));
+        }
+
+        if ch == '\n' {
+            location.line += 1;
+            location.column = 1;
+        } else {
+            location.column += 1;
+        }
+    }
+
+    tokens
+}
@@ -2,4 +2,5 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub mod instructionize;
 pub mod lexer;
+pub mod location;
 pub mod token;
@@ -0,0 +1,34 @@
+use core::fmt;
+
+/// A position in the source of a Brainfuck program. Lines and columns start at
+/// 1, and columns count characters rather than bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Location
+{
+    pub line:   usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The range of source between two tokens, including both of them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Span
+{
+    pub start: Location,
+    pub end:   Location,
+}
+
+impl fmt::Display for Span
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
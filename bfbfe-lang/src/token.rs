@@ -3,7 +3,8 @@
 /// structure, or data of a Brainfuck program other than which Brainfuck
 /// instruction it represents.
 ///
-/// `Token` maps 1:1 with the eight Brainfuck commands.
+/// `Token` maps 1:1 with the eight Brainfuck commands, plus the debug
+/// extension when it is enabled by the [`Dialect`](crate::lexer::Dialect).
 ///
 /// | Character | Name               |
 /// |-----------|--------------------|
@@ -15,6 +16,7 @@
 /// | `,`       | `ReadByte`         |
 /// | `[`       | `JumpForward`      |
 /// | `]`       | `JumpBackward`     |
+/// | `#`       | `Debug`            |
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Token
 {
@@ -26,4 +28,5 @@ pub enum Token
     ReadByte,
     JumpForward,
     JumpBackward,
+    Debug,
 }
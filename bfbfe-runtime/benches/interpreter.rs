@@ -0,0 +1,81 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::fs;
+use std::io::empty;
+use std::io::sink;
+use std::process::Command;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_whole_program;
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+use bfbfe_transpile::backend::c_backend::compile_to_c;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+const PROGRAMS: &[(&str, &str)] = &[
+    ("squares", include_str!("programs/squares.b")),
+    ("nested", include_str!("programs/nested.b")),
+];
+
+fn optimized_ir(program: &str) -> IRBlock
+{
+    let tokens = tokenize_whole_program(&program.chars().collect::<Vec<_>>());
+    instructionize(&tokens)
+        .expect("benchmark program is malformed")
+        .optimize()
+}
+
+fn interpreter(c: &mut Criterion)
+{
+    let mut group = c.benchmark_group("interpreter");
+
+    for (name, program) in PROGRAMS {
+        let bytecode = Bytecode::compile(&optimized_ir(program)).expect("benchmark program failed to compile");
+
+        group.bench_function(*name, |b| {
+            b.iter(|| Interpreter::<u8>::new().run(&bytecode, &mut empty(), &mut sink()));
+        });
+    }
+
+    group.finish();
+}
+
+/// Runs the output of the C backend compiled with `cc -O2` as a baseline. This
+/// includes the cost of spawning a process, and is skipped when no C compiler
+/// is available.
+fn c_backend(c: &mut Criterion)
+{
+    let dir = std::env::temp_dir().join("bfbfe-bench");
+    fs::create_dir_all(&dir).expect("failed to create benchmark directory");
+
+    let mut group = c.benchmark_group("c_backend");
+
+    for (name, program) in PROGRAMS {
+        let source = dir.join(format!("{name}.c"));
+        let executable = dir.join(name);
+        fs::write(&source, compile_to_c(&optimized_ir(program))).expect("failed to write C source");
+
+        let compiled = Command::new("cc")
+            .arg("-O2")
+            .arg("-o")
+            .arg(&executable)
+            .arg(&source)
+            .status();
+        if !matches!(compiled, Ok(status) if status.success()) {
+            eprintln!("skipping C backend benchmark for {name}: `cc -O2` failed");
+            continue;
+        }
+
+        group.bench_function(*name, |b| {
+            b.iter(|| Command::new(&executable).output());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, interpreter, c_backend);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+Three nested loops counting down in steps of two so none of them can be
+turned into multiplication
+
+-[>--[>--[-->+<]<--]<-]>>>.
@@ -0,0 +1,388 @@
+use core::fmt;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::instructionize::InstructionizingError;
+use bfbfe_lang::lexer::tokenize_with_locations;
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_lang::location::Location;
+use bfbfe_lang::token::Token;
+
+use crate::runtime_error::RuntimeError;
+
+/// A single operation of the flattened bytecode. Offsets are relative to the
+/// pointer, and jump targets are absolute indices into the bytecode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Op
+{
+    /// Move the pointer by the given amount.
+    Traverse(isize),
+    /// Add `val` to the cell at `pos`.
+    Mutate
+    {
+        pos: isize, val: u32
+    },
+    /// Set the cell at `pos` to `val`.
+    Set
+    {
+        pos: isize, val: u32
+    },
+    /// Add the current cell multiplied by `factor` to the cell at `pos`.
+    MulAdd
+    {
+        pos: isize, factor: u32
+    },
+    /// Output the cell at `pos`.
+    Output
+    {
+        pos: isize
+    },
+    /// Output the given byte.
+    OutputByte(u8),
+    /// Read a byte into the cell at `pos`.
+    Read
+    {
+        pos: isize
+    },
+    /// Move the pointer by the given stride until it points to a zero cell.
+    Scan(isize),
+    /// Jump to the target if the current cell is zero.
+    JumpIfZero(usize),
+    /// Jump to the target if the current cell is non-zero.
+    JumpIfNonZero(usize),
+    /// Stop the [`Debugger`](crate::debugger::Debugger). Does nothing
+    /// otherwise.
+    Breakpoint,
+}
+
+impl fmt::Display for Op
+{
+    /// Formats the operation like assembly, with constants shown as signed
+    /// 32-bit integers, so that `-` reads as `-1` rather than `4294967295`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let signed = |val: u32| i32::from_ne_bytes(val.to_ne_bytes());
+
+        match *self {
+            Self::Traverse(val) => write!(f, "traverse {val:+}"),
+            Self::Mutate {
+                pos,
+                val,
+            } => write!(f, "mutate [{pos:+}] {:+}", signed(val)),
+            Self::Set {
+                pos,
+                val,
+            } => write!(f, "set [{pos:+}] {}", signed(val)),
+            Self::MulAdd {
+                pos,
+                factor,
+            } => write!(f, "muladd [{pos:+}] {:+}", signed(factor)),
+            Self::Output {
+                pos,
+            } => write!(f, "output [{pos:+}]"),
+            Self::OutputByte(byte) => write!(f, "output {:?}", char::from(byte)),
+            Self::Read {
+                pos,
+            } => write!(f, "read [{pos:+}]"),
+            Self::Scan(val) => write!(f, "scan {val:+}"),
+            Self::JumpIfZero(target) => write!(f, "jz {target}"),
+            Self::JumpIfNonZero(target) => write!(f, "jnz {target}"),
+            Self::Breakpoint => write!(f, "breakpoint"),
+        }
+    }
+}
+
+/// Optimized BFBFE IR flattened into a dense list of operations, with every
+/// loop turned into a pair of jumps with precomputed targets. Constants are
+/// stored modulo 2<sup>32</sup> so the same bytecode runs with any
+/// [`Cell`](crate::cell::Cell) width.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bytecode
+{
+    pub ops: Vec<Op>,
+}
+
+impl Bytecode
+{
+    pub fn compile(block: &IRBlock) -> Result<Self, RuntimeError>
+    {
+        let mut ops = Vec::new();
+
+        _compile(block, &mut ops, true)?;
+
+        Ok(Self {
+            ops,
+        })
+    }
+
+    /// Compiles every instruction into exactly one operation and every loop
+    /// into a pair of jumps, without recognizing any loop idioms. For
+    /// unoptimized IR, this keeps operations in the order of the tokens they
+    /// came from.
+    pub fn compile_unoptimized(block: &IRBlock) -> Result<Self, RuntimeError>
+    {
+        let mut ops = Vec::new();
+
+        _compile(block, &mut ops, false)?;
+
+        Ok(Self {
+            ops,
+        })
+    }
+
+    /// Compiles `source` without optimizing it, returning its unoptimized IR
+    /// along with the location of the token every operation came from.
+    pub(crate) fn compile_with_locations(
+        source: &str,
+        dialect: Dialect,
+    ) -> Result<(IRBlock, Self, Vec<Location>), RuntimeError>
+    {
+        let (tokens, locations): (Vec<Token>, Vec<Location>) =
+            tokenize_with_locations(&source.chars().collect::<Vec<_>>(), dialect)
+                .into_iter()
+                .unzip();
+        let block = instructionize(&tokens)?;
+        let bytecode = Self::compile_unoptimized(&block)?;
+
+        // Instructionizing silently drops a JumpBackward that precedes its
+        // JumpForward, which would misalign every following location
+        if bytecode.ops.len() != locations.len() {
+            return Err(InstructionizingError::UnmatchedJumpBackward.into());
+        }
+
+        Ok((block, bytecode, locations))
+    }
+}
+
+/// A kind of loop that compiles into something other than a pair of jumps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoopIdiom
+{
+    /// Sets the current cell to zero, such as `[-]`.
+    Clear,
+    /// Moves the pointer until it finds a zero cell, such as `[>>]`.
+    Scan,
+    /// Adds multiples of the current cell to other cells, such as `[->++<]`.
+    Multiply,
+}
+
+impl LoopIdiom
+{
+    /// Recognizes the idiom of a loop with the given body, if any. Bodies
+    /// should be optimized first, the same way they are before compiling.
+    pub fn recognize(body: &IRBlock) -> Option<Self>
+    {
+        if scan_stride(body).is_some() {
+            Some(Self::Scan)
+        } else {
+            multiply_loop(body).map(|mul_adds| {
+                if mul_adds.is_empty() {
+                    Self::Clear
+                } else {
+                    Self::Multiply
+                }
+            })
+        }
+    }
+}
+
+impl fmt::Display for LoopIdiom
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::Clear => write!(f, "clear loop"),
+            Self::Scan => write!(f, "scan loop"),
+            Self::Multiply => write!(f, "multiply loop"),
+        }
+    }
+}
+
+/// Reduces `val` modulo 2<sup>32</sup>, which truncates to the way cells of
+/// any supported width wrap.
+fn wrap(val: isize) -> u32
+{
+    let val = i64::try_from(val).unwrap_or_default();
+    u32::try_from(val & 0xFFFF_FFFF).unwrap_or_default()
+}
+
+/// Recognizes loops that only move the pointer, such as `[>>]`.
+fn scan_stride(block: &IRBlock) -> Option<isize>
+{
+    match block.as_slice() {
+        [IRInstruction::TraverseBy {
+            val,
+        }] if *val != 0 => Some(*val),
+        _ => None,
+    }
+}
+
+/// Recognizes loops that only mutate cells without moving the pointer and
+/// step the current cell by one each iteration, such as `[->+>++<<]`.
+///
+/// These run as many times as it takes for the current cell to reach zero, so
+/// each other cell gets the current cell multiplied by its mutation added to
+/// it. The resulting `MulAdd` operations have to be followed by clearing the
+/// current cell.
+fn multiply_loop(block: &IRBlock) -> Option<Vec<Op>>
+{
+    let mut mutations = Vec::<(isize, isize)>::new();
+    for instr in block.iter() {
+        let IRInstruction::MutateValue {
+            pos,
+            val,
+        } = instr
+        else {
+            return None;
+        };
+
+        match mutations.iter_mut().find(|(p, _)| p == pos) {
+            Some((_, v)) => *v += val,
+            None => mutations.push((*pos, *val)),
+        }
+    }
+
+    let step = mutations.iter().find(|(pos, _)| *pos == 0).map(|(_, val)| wrap(*val))?;
+    let ops = mutations.into_iter().filter(|(pos, _)| *pos != 0);
+
+    match step {
+        // Counting down runs the loop as many times as the current cell's value
+        u32::MAX => Some(
+            ops.map(|(pos, val)| Op::MulAdd {
+                pos,
+                factor: wrap(val),
+            })
+            .collect(),
+        ),
+
+        // Counting up runs the loop as many times as the current cell's negated value
+        1 => Some(
+            ops.map(|(pos, val)| Op::MulAdd {
+                pos,
+                factor: wrap(-val),
+            })
+            .collect(),
+        ),
+
+        _ => None,
+    }
+}
+
+fn _compile(block: &IRBlock, ops: &mut Vec<Op>, idioms: bool) -> Result<(), RuntimeError>
+{
+    for instr in block.iter() {
+        match instr {
+            IRInstruction::BeginProgram | IRInstruction::EndProgram => {}
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                ops.push(Op::Traverse(*val));
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                ops.push(Op::Mutate {
+                    pos: *pos,
+                    val: wrap(*val),
+                });
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                ops.push(Op::Set {
+                    pos: *pos,
+                    val: wrap(*val),
+                });
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                ops.extend(poslst.iter().map(|&pos| Op::Output {
+                    pos,
+                }));
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                ops.extend(poslst.iter().map(|&pos| Op::Read {
+                    pos,
+                }));
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                ops.extend(bytes.iter().copied().map(Op::OutputByte));
+            }
+
+            // Jumps only test the current cell, so the pointer is moved onto the
+            // cell a loop is on for as long as it runs
+            IRInstruction::ConditionalBlock(pos, _) | IRInstruction::IfNonZero(pos, _) if *pos != 0 => {
+                let mut moved = instr.clone();
+                if let IRInstruction::ConditionalBlock(pos, block) | IRInstruction::IfNonZero(pos, block) = &mut moved {
+                    block.offset_by(-*pos);
+                    *pos = 0;
+                }
+
+                ops.push(Op::Traverse(*pos));
+                _compile(&IRBlock::with_instructions(vec![moved]), ops, idioms)?;
+                ops.push(Op::Traverse(-*pos));
+            }
+
+            IRInstruction::ConditionalBlock(_, block) => {
+                if let Some(stride) = scan_stride(block).filter(|_| idioms) {
+                    ops.push(Op::Scan(stride));
+                } else if let Some(mul_adds) = multiply_loop(block).filter(|_| idioms) {
+                    // The loop never touches the other cells if it doesn't
+                    // run, and they may well be outside of the tape
+                    if !mul_adds.is_empty() {
+                        ops.push(Op::JumpIfZero(ops.len() + mul_adds.len() + 2));
+                    }
+                    ops.extend(mul_adds);
+                    ops.push(Op::Set {
+                        pos: 0, val: 0
+                    });
+                } else {
+                    let start = ops.len();
+                    ops.push(Op::JumpIfZero(0));
+                    _compile(block, ops, idioms)?;
+                    let end = ops.len();
+                    ops.push(Op::JumpIfNonZero(start + 1));
+
+                    if let Some(op) = ops.get_mut(start) {
+                        *op = Op::JumpIfZero(end + 1);
+                    }
+                }
+            }
+
+            IRInstruction::IfNonZero(_, block) => {
+                let start = ops.len();
+                ops.push(Op::JumpIfZero(0));
+                _compile(block, ops, idioms)?;
+                let end = ops.len();
+
+                if let Some(op) = ops.get_mut(start) {
+                    *op = Op::JumpIfZero(end);
+                }
+            }
+
+            IRInstruction::Debug => {
+                ops.push(Op::Breakpoint);
+            }
+
+            _ => {
+                return Err(RuntimeError::UnrecognizedInstruction);
+            }
+        }
+    }
+
+    Ok(())
+}
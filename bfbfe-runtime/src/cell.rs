@@ -0,0 +1,76 @@
+use core::fmt::Debug;
+
+/// An unsigned integer type that can be used as a tape cell.
+///
+/// Bytecode stores its constants modulo 2<sup>32</sup>, which truncate
+/// correctly into every supported cell width.
+pub trait Cell: Copy + Debug + Default + Eq
+{
+    /// The value stored when reading past the end of input under
+    /// [`EofPolicy::Max`](crate::interpreter::EofPolicy::Max).
+    const MAX: Self;
+
+    /// Truncates a bytecode constant to the width of the cell.
+    fn truncate(val: u32) -> Self;
+
+    /// Widens a byte of input to the width of the cell.
+    fn from_byte(byte: u8) -> Self;
+
+    /// Truncates the cell to the byte that gets output.
+    fn to_byte(self) -> u8;
+
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    #[inline(always)]
+    fn is_zero(self) -> bool
+    {
+        self == Self::default()
+    }
+}
+
+macro_rules! impl_cell {
+    ($ty:ty) => {
+        impl Cell for $ty
+        {
+            const MAX: Self = <$ty>::MAX;
+
+            #[inline(always)]
+            fn truncate(val: u32) -> Self
+            {
+                // Masking first makes the conversion infallible
+                Self::try_from(val & u32::from(<$ty>::MAX)).unwrap_or_default()
+            }
+
+            #[inline(always)]
+            fn from_byte(byte: u8) -> Self
+            {
+                Self::from(byte)
+            }
+
+            #[inline(always)]
+            fn to_byte(self) -> u8
+            {
+                let [.., low] = self.to_be_bytes();
+                low
+            }
+
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self
+            {
+                <$ty>::wrapping_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn wrapping_mul(self, rhs: Self) -> Self
+            {
+                <$ty>::wrapping_mul(self, rhs)
+            }
+        }
+    };
+}
+
+impl_cell!(u8);
+impl_cell!(u16);
+impl_cell!(u32);
@@ -0,0 +1,255 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_lang::location::Location;
+
+use crate::bytecode::Bytecode;
+use crate::bytecode::Op;
+use crate::cell::Cell;
+use crate::interpreter::Interpreter;
+use crate::runtime_error::RuntimeError;
+
+/// Why the [`Debugger`] stopped executing the program.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stop<C = u8>
+{
+    /// The requested amount of operations was executed.
+    Stepped,
+    /// The next operation has a breakpoint at the given location, or is a
+    /// breakpoint in the source.
+    Breakpoint(Location),
+    /// The last operation changed a watched cell.
+    Watchpoint
+    {
+        cell: usize, old: C, new: C
+    },
+    /// The program ran to completion.
+    Finished,
+}
+
+/// Executes a Brainfuck program one operation at a time, keeping track of
+/// where in the source every operation came from.
+///
+/// The program is left unoptimized, so every operation corresponds to exactly
+/// one token of the source.
+#[derive(Clone, Debug)]
+pub struct Debugger<C = u8>
+{
+    bytecode:    Bytecode,
+    /// The location of the token each operation came from, by index.
+    locations:   Vec<Location>,
+    interpreter: Interpreter<C>,
+    pc:          usize,
+    steps:       u64,
+    /// Indices of operations to stop before.
+    breakpoints: Vec<usize>,
+    /// Indices of cells to stop after changes to.
+    watchpoints: Vec<usize>,
+}
+
+impl<C: Cell> Debugger<C>
+{
+    pub fn new(source: &str, dialect: Dialect) -> Result<Self, RuntimeError>
+    {
+        let (_, bytecode, locations) = Bytecode::compile_with_locations(source, dialect)?;
+
+        Ok(Self {
+            bytecode,
+            locations,
+            interpreter: Interpreter::new(),
+            pc: 0,
+            steps: 0,
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+        })
+    }
+
+    #[inline]
+    pub const fn pc(&self) -> usize
+    {
+        self.pc
+    }
+
+    /// The amount of operations executed so far.
+    #[inline]
+    pub const fn steps(&self) -> u64
+    {
+        self.steps
+    }
+
+    #[inline]
+    pub fn tape(&self) -> &[C]
+    {
+        self.interpreter.tape()
+    }
+
+    #[inline]
+    pub const fn ptr(&self) -> usize
+    {
+        self.interpreter.ptr()
+    }
+
+    #[inline]
+    pub const fn is_finished(&self) -> bool
+    {
+        self.pc >= self.bytecode.ops.len()
+    }
+
+    /// The operation that will be executed next.
+    pub fn op(&self) -> Option<Op>
+    {
+        self.bytecode.ops.get(self.pc).copied()
+    }
+
+    /// The location in the source of the operation that will be executed next.
+    pub fn location(&self) -> Option<Location>
+    {
+        self.locations.get(self.pc).copied()
+    }
+
+    /// Sets a breakpoint on the first operation at or after `location`,
+    /// returning where it ended up.
+    pub fn add_breakpoint(&mut self, location: Location) -> Option<Location>
+    {
+        let index = self.locations.iter().position(|&l| l >= location)?;
+        if !self.breakpoints.contains(&index) {
+            self.breakpoints.push(index);
+        }
+        self.locations.get(index).copied()
+    }
+
+    /// Removes the breakpoint at exactly `location`, returning whether there
+    /// was one.
+    pub fn remove_breakpoint(&mut self, location: Location) -> bool
+    {
+        let len = self.breakpoints.len();
+        let locations = &self.locations;
+        self.breakpoints
+            .retain(|&index| locations.get(index) != Some(&location));
+        self.breakpoints.len() != len
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = Location> + '_
+    {
+        self.breakpoints
+            .iter()
+            .filter_map(|&index| self.locations.get(index).copied())
+    }
+
+    /// Watches the cell at `cell`, returning whether it is on the tape.
+    pub fn add_watchpoint(&mut self, cell: usize) -> bool
+    {
+        if cell >= self.tape().len() {
+            return false;
+        }
+
+        if !self.watchpoints.contains(&cell) {
+            self.watchpoints.push(cell);
+        }
+        true
+    }
+
+    /// Stops watching the cell at `cell`, returning whether it was watched.
+    pub fn remove_watchpoint(&mut self, cell: usize) -> bool
+    {
+        let len = self.watchpoints.len();
+        self.watchpoints.retain(|&watched| watched != cell);
+        self.watchpoints.len() != len
+    }
+
+    pub fn watchpoints(&self) -> &[usize]
+    {
+        &self.watchpoints
+    }
+
+    /// Executes up to `count` operations.
+    pub fn step(&mut self, count: u64, input: &mut dyn Read, output: &mut dyn Write) -> Result<Stop<C>, RuntimeError>
+    {
+        let target = self.steps.saturating_add(count.max(1));
+        self.resume(input, output, |debugger| debugger.steps >= target)
+    }
+
+    /// Executes the next operation, or the whole loop if the next operation
+    /// begins one.
+    pub fn step_over(&mut self, input: &mut dyn Read, output: &mut dyn Write) -> Result<Stop<C>, RuntimeError>
+    {
+        match self.op() {
+            // Both skipping and leaving the loop continue after its end
+            Some(Op::JumpIfZero(end)) => self.resume(input, output, |debugger| debugger.pc == end),
+            _ => self.step(1, input, output),
+        }
+    }
+
+    /// Executes the program until it stops at a breakpoint or watchpoint, or
+    /// finishes.
+    pub fn run(&mut self, input: &mut dyn Read, output: &mut dyn Write) -> Result<Stop<C>, RuntimeError>
+    {
+        self.resume(input, output, |_| false)
+    }
+
+    /// Executes operations until `done` returns true. The first operation is
+    /// always executed, so resuming from a breakpoint doesn't stop at it again.
+    fn resume(
+        &mut self,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+        mut done: impl FnMut(&Self) -> bool,
+    ) -> Result<Stop<C>, RuntimeError>
+    {
+        loop {
+            let stop = self.advance(input, output);
+            if !matches!(stop, Ok(None)) {
+                output.flush()?;
+                return stop.map(|stop| stop.unwrap_or(Stop::Finished));
+            }
+
+            if self.breakpoints.contains(&self.pc) || self.op() == Some(Op::Breakpoint) {
+                output.flush()?;
+                return Ok(self.location().map_or(Stop::Finished, Stop::Breakpoint));
+            }
+
+            if done(self) {
+                output.flush()?;
+                return Ok(Stop::Stepped);
+            }
+        }
+    }
+
+    /// Executes a single operation, returning why execution has to stop after
+    /// it, if it does.
+    fn advance(&mut self, input: &mut dyn Read, output: &mut dyn Write) -> Result<Option<Stop<C>>, RuntimeError>
+    {
+        let Some(op) = self.op() else {
+            return Ok(Some(Stop::Finished));
+        };
+
+        let tape = self.interpreter.tape();
+        let watched = self
+            .watchpoints
+            .iter()
+            .filter_map(|&cell| Some((cell, *tape.get(cell)?)))
+            .collect::<Vec<_>>();
+
+        self.pc = self.interpreter.step(op, self.pc, input, output)?;
+        self.steps += 1;
+
+        let tape = self.interpreter.tape();
+        for (cell, old) in watched {
+            match tape.get(cell) {
+                Some(&new) if new != old => {
+                    return Ok(Some(Stop::Watchpoint {
+                        cell,
+                        old,
+                        new,
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(self.is_finished().then_some(Stop::Finished))
+    }
+}
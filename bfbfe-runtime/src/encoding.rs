@@ -0,0 +1,92 @@
+#![allow(clippy::std_instead_of_core)]
+
+//! Primitives shared by the binary file formats, such as traces and
+//! snapshots. Integers are stored as unsigned LEB128, with signed integers
+//! zigzag encoded first.
+
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+
+use crate::runtime_error::RuntimeError;
+
+pub fn write_varint(writer: &mut dyn Write, val: usize) -> Result<(), RuntimeError>
+{
+    let mut val = u64::try_from(val).unwrap_or(u64::MAX);
+    loop {
+        let [.., byte] = val.to_be_bytes();
+        let byte = byte & 0x7F;
+        val >>= 7_u8;
+
+        if val == 0 {
+            writer.write_all(&[byte])?;
+            return Ok(());
+        }
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+pub fn read_varint(reader: &mut dyn Read) -> Result<usize, RuntimeError>
+{
+    let mut val = 0_u64;
+    for shift in (0_u8..64).step_by(7) {
+        let [byte] = read_array(reader)?;
+        val |= u64::from(byte & 0x7F) << shift;
+
+        if byte & 0x80 == 0 {
+            return usize::try_from(val).map_err(|_| RuntimeError::MalformedFile("integer out of range"));
+        }
+    }
+
+    Err(RuntimeError::MalformedFile("integer out of range"))
+}
+
+pub fn write_signed(writer: &mut dyn Write, val: isize) -> Result<(), RuntimeError>
+{
+    // Moves the sign to the lowest bit, so small magnitudes stay short
+    let magnitude = val.unsigned_abs();
+    write_varint(
+        writer,
+        if val < 0 {
+            ((magnitude - 1) << 1_u8) | 1
+        } else {
+            magnitude << 1_u8
+        },
+    )
+}
+
+pub fn read_signed(reader: &mut dyn Read) -> Result<isize, RuntimeError>
+{
+    let val = read_varint(reader)?;
+    let magnitude = isize::try_from(val >> 1_u8).map_err(|_| RuntimeError::MalformedFile("integer out of range"))?;
+    Ok(if val & 1 == 0 { magnitude } else { -magnitude - 1 })
+}
+
+pub fn write_bytes(writer: &mut dyn Write, bytes: &[u8]) -> Result<(), RuntimeError>
+{
+    write_varint(writer, bytes.len())?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+pub fn read_bytes(reader: &mut dyn Read) -> Result<Vec<u8>, RuntimeError>
+{
+    let len = read_varint(reader)?;
+    let mut bytes = Vec::new();
+    Read::take(reader, u64::try_from(len).unwrap_or(u64::MAX)).read_to_end(&mut bytes)?;
+
+    if bytes.len() != len {
+        return Err(RuntimeError::MalformedFile("unexpected end of file"));
+    }
+    Ok(bytes)
+}
+
+pub fn read_array<const N: usize>(reader: &mut dyn Read) -> Result<[u8; N], RuntimeError>
+{
+    let mut array = [0_u8; N];
+    reader.read_exact(&mut array).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => RuntimeError::MalformedFile("unexpected end of file"),
+        _ => e.into(),
+    })?;
+    Ok(array)
+}
@@ -0,0 +1,403 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::time::Instant;
+
+use crate::bytecode::Bytecode;
+use crate::bytecode::Op;
+use crate::cell::Cell;
+use crate::limits::ExecutionLimits;
+use crate::limits::Limit;
+use crate::runtime_error::RuntimeError;
+use crate::TAPE_SIZE;
+
+/// How many steps are executed between checks of the deadline, since reading
+/// the clock is far more expensive than executing an operation. Has to be a
+/// power of two.
+const DEADLINE_CHECK_INTERVAL: u64 = 0x1_0000;
+
+/// Resources used by a single run, checked against its limits as they are
+/// consumed.
+struct Usage
+{
+    limits:   ExecutionLimits,
+    deadline: Option<Instant>,
+    steps:    u64,
+    output:   u64,
+    /// Which cells have been accessed, only tracked if the amount of cells is
+    /// limited.
+    touched:  Vec<bool>,
+    cells:    usize,
+}
+
+impl Usage
+{
+    fn new(limits: &ExecutionLimits) -> Self
+    {
+        Self {
+            limits:   *limits,
+            deadline: limits.timeout.map(|timeout| Instant::now() + timeout),
+            steps:    0,
+            output:   0,
+            touched:  if limits.max_cells.is_some() {
+                vec![false; TAPE_SIZE]
+            } else {
+                Vec::new()
+            },
+            cells:    0,
+        }
+    }
+
+    #[inline(always)]
+    fn step(&mut self) -> Result<(), Limit>
+    {
+        self.steps += 1;
+
+        if let Some(max_steps) = self.limits.max_steps {
+            if self.steps > max_steps {
+                return Err(Limit::Steps(max_steps));
+            }
+        }
+
+        if self.steps & (DEADLINE_CHECK_INTERVAL - 1) == 0 {
+            if let (Some(deadline), Some(timeout)) = (self.deadline, self.limits.timeout) {
+                if Instant::now() >= deadline {
+                    return Err(Limit::Timeout(timeout));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn touch(&mut self, index: usize) -> Result<(), Limit>
+    {
+        if let Some(max_cells) = self.limits.max_cells {
+            if let Some(touched @ false) = self.touched.get_mut(index) {
+                *touched = true;
+                self.cells += 1;
+
+                if self.cells > max_cells {
+                    return Err(Limit::Cells(max_cells));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline(always)]
+    fn output(&mut self) -> Result<(), Limit>
+    {
+        self.output += 1;
+
+        match self.limits.max_output {
+            Some(max_output) if self.output > max_output => Err(Limit::Output(max_output)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// What a read stores in its cell once the input is exhausted.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EofPolicy
+{
+    /// Store the largest value a cell can hold, i.e. -1. This matches the C
+    /// backend, which stores the `EOF` returned by `getchar`.
+    #[default]
+    Max,
+    /// Store zero.
+    Zero,
+    /// Leave the cell as it was.
+    Unchanged,
+}
+
+/// Executes [`Bytecode`] against a tape of [`TAPE_SIZE`] cells, each of which
+/// is a `C`.
+///
+/// The tape and pointer persist between runs, so several programs can be
+/// executed against the same machine state.
+#[derive(Clone, Debug)]
+pub struct Interpreter<C = u8>
+{
+    tape: Vec<C>,
+    ptr:  usize,
+    eof:  EofPolicy,
+}
+
+impl<C: Cell> Default for Interpreter<C>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<C: Cell> Interpreter<C>
+{
+    pub fn new() -> Self
+    {
+        Self {
+            tape: vec![C::default(); TAPE_SIZE],
+            ptr:  0,
+            eof:  EofPolicy::default(),
+        }
+    }
+
+    /// Creates an interpreter from a tape and pointer, such as those returned
+    /// by [`into_parts`](Self::into_parts).
+    pub const fn from_parts(tape: Vec<C>, ptr: usize) -> Self
+    {
+        Self {
+            tape,
+            ptr,
+            eof: EofPolicy::Max,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_eof_policy(mut self, eof: EofPolicy) -> Self
+    {
+        self.eof = eof;
+        self
+    }
+
+    #[inline]
+    pub fn tape(&self) -> &[C]
+    {
+        &self.tape
+    }
+
+    #[inline]
+    pub const fn ptr(&self) -> usize
+    {
+        self.ptr
+    }
+
+    /// Consumes the interpreter, returning its tape and pointer.
+    pub fn into_parts(self) -> (Vec<C>, usize)
+    {
+        (self.tape, self.ptr)
+    }
+
+    pub fn run(&mut self, bytecode: &Bytecode, input: &mut dyn Read, output: &mut dyn Write)
+        -> Result<(), RuntimeError>
+    {
+        self.run_with_limits(bytecode, input, output, &ExecutionLimits::default())
+    }
+
+    /// Runs `bytecode` until it finishes or exceeds one of `limits`, in which
+    /// case a [`RuntimeError::LimitExceeded`] reports the limit along with the
+    /// operation and pointer it was hit at.
+    pub fn run_with_limits(
+        &mut self,
+        bytecode: &Bytecode,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+        limits: &ExecutionLimits,
+    ) -> Result<(), RuntimeError>
+    {
+        self.run_from(bytecode, 0, input, output, limits)
+    }
+
+    /// Like [`run_with_limits`](Self::run_with_limits), but starts at the
+    /// operation at index `pc`, so that a run stopped by a limit can be
+    /// resumed from the `pc` it reported.
+    pub fn run_from(
+        &mut self,
+        bytecode: &Bytecode,
+        mut pc: usize,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+        limits: &ExecutionLimits,
+    ) -> Result<(), RuntimeError>
+    {
+        let ops = bytecode.ops.as_slice();
+        let mut usage = Usage::new(limits);
+
+        while let Some(&op) = ops.get(pc) {
+            usage.step().map_err(|limit| RuntimeError::LimitExceeded {
+                limit,
+                pc,
+                ptr: self.ptr,
+            })?;
+
+            pc = self.execute(op, pc, input, output, &mut usage)?;
+        }
+
+        output.flush()?;
+
+        Ok(())
+    }
+
+    /// Runs at most `steps` operations of `bytecode`, starting at index `pc`.
+    /// Returns the index to continue from if the program has not finished.
+    pub fn run_for(
+        &mut self,
+        bytecode: &Bytecode,
+        pc: usize,
+        steps: u64,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<Option<usize>, RuntimeError>
+    {
+        let limits = ExecutionLimits {
+            max_steps: Some(steps),
+            ..ExecutionLimits::default()
+        };
+
+        // Limits are checked before an operation changes anything, and a scan
+        // stopped midway simply continues from where the pointer is
+        match self.run_from(bytecode, pc, input, output, &limits) {
+            Ok(()) => Ok(None),
+            Err(RuntimeError::LimitExceeded {
+                limit: Limit::Steps(_),
+                pc,
+                ..
+            }) => Ok(Some(pc)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Executes a single operation without any limits, as if it was found at
+    /// index `pc` of its bytecode, and returns the index of the operation to
+    /// execute next. Output is only flushed before reading input.
+    pub fn step(
+        &mut self,
+        op: Op,
+        pc: usize,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<usize, RuntimeError>
+    {
+        self.execute(op, pc, input, output, &mut Usage::new(&ExecutionLimits::default()))
+    }
+
+    #[inline(always)]
+    fn execute(
+        &mut self,
+        op: Op,
+        pc: usize,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+        usage: &mut Usage,
+    ) -> Result<usize, RuntimeError>
+    {
+        macro_rules! check {
+            ($usage:expr) => {
+                $usage.map_err(|limit| RuntimeError::LimitExceeded {
+                    limit,
+                    pc,
+                    ptr: self.ptr,
+                })?
+            };
+        }
+
+        macro_rules! cell {
+            ($pos:expr) => {{
+                let index = self
+                    .ptr
+                    .checked_add_signed($pos)
+                    .filter(|&index| index < self.tape.len())
+                    .ok_or(RuntimeError::PointerOutOfBounds)?;
+                check!(usage.touch(index));
+                self.tape.get_mut(index).ok_or(RuntimeError::PointerOutOfBounds)?
+            }};
+        }
+
+        match op {
+            Op::Traverse(val) => {
+                self.ptr = self.ptr.wrapping_add_signed(val);
+            }
+
+            Op::Mutate {
+                pos,
+                val,
+            } => {
+                let cell = cell!(pos);
+                *cell = cell.wrapping_add(C::truncate(val));
+            }
+
+            Op::Set {
+                pos,
+                val,
+            } => {
+                *cell!(pos) = C::truncate(val);
+            }
+
+            Op::MulAdd {
+                pos,
+                factor,
+            } => {
+                let value = *cell!(0);
+                let cell = cell!(pos);
+                *cell = cell.wrapping_add(value.wrapping_mul(C::truncate(factor)));
+            }
+
+            Op::Output {
+                pos,
+            } => {
+                check!(usage.output());
+                output.write_all(&[cell!(pos).to_byte()])?;
+            }
+
+            Op::OutputByte(byte) => {
+                check!(usage.output());
+                output.write_all(&[byte])?;
+            }
+
+            Op::Read {
+                pos,
+            } => {
+                // Make sure prompts are visible before blocking on input
+                output.flush()?;
+
+                let mut byte = [0_u8];
+                let cell = cell!(pos);
+                match input.read_exact(&mut byte) {
+                    Ok(()) => {
+                        let [byte] = byte;
+                        *cell = C::from_byte(byte);
+                    }
+
+                    Err(e) if e.kind() == ErrorKind::UnexpectedEof => match self.eof {
+                        EofPolicy::Max => *cell = C::MAX,
+                        EofPolicy::Zero => *cell = C::default(),
+                        EofPolicy::Unchanged => {}
+                    },
+
+                    Err(e) => {
+                        return Err(e.into());
+                    }
+                }
+            }
+
+            Op::Scan(val) => {
+                while !cell!(0).is_zero() {
+                    check!(usage.step());
+                    self.ptr = self.ptr.wrapping_add_signed(val);
+                }
+            }
+
+            Op::JumpIfZero(target) => {
+                if cell!(0).is_zero() {
+                    return Ok(target);
+                }
+            }
+
+            Op::JumpIfNonZero(target) => {
+                if !cell!(0).is_zero() {
+                    return Ok(target);
+                }
+            }
+
+            Op::Breakpoint => {}
+        }
+
+        Ok(pc + 1)
+    }
+}
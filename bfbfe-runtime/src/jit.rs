@@ -0,0 +1,425 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use cranelift_codegen::entity::EntityRef;
+use cranelift_codegen::ir::condcodes::IntCC;
+use cranelift_codegen::ir::types;
+use cranelift_codegen::ir::AbiParam;
+use cranelift_codegen::ir::Block;
+use cranelift_codegen::ir::FuncRef;
+use cranelift_codegen::ir::InstBuilder;
+use cranelift_codegen::ir::MemFlags;
+use cranelift_codegen::ir::Value;
+use cranelift_frontend::FunctionBuilder;
+use cranelift_frontend::FunctionBuilderContext;
+use cranelift_frontend::Variable;
+use cranelift_jit::JITBuilder;
+use cranelift_jit::JITModule;
+use cranelift_module::default_libcall_names;
+use cranelift_module::Linkage;
+use cranelift_module::Module;
+
+use crate::runtime_error::RuntimeError;
+use crate::TAPE_SIZE;
+
+const STATUS_OK: i32 = 0;
+const STATUS_POINTER_OUT_OF_BOUNDS: i32 = 1;
+
+type EntryPoint = for<'a> unsafe extern "C" fn(*mut u8, *mut Io<'a>) -> i32;
+
+/// I/O state handed to compiled programs. Errors can't be propagated through
+/// JIT compiled frames, so the first one is stashed and reported once the
+/// program returns.
+struct Io<'a>
+{
+    input:  &'a mut dyn Read,
+    output: &'a mut dyn Write,
+    error:  Option<std::io::Error>,
+}
+
+extern "C" fn output_byte(io: *mut Io<'_>, byte: u8)
+{
+    // SAFETY: Compiled programs only ever pass along the pointer `JitProgram::run`
+    // hands them
+    let io = unsafe { &mut *io };
+    if io.error.is_none() {
+        io.error = io.output.write_all(&[byte]).err();
+    }
+}
+
+extern "C" fn input_byte(io: *mut Io<'_>) -> u8
+{
+    // SAFETY: Compiled programs only ever pass along the pointer `JitProgram::run`
+    // hands them
+    let io = unsafe { &mut *io };
+    if io.error.is_some() {
+        return 255;
+    }
+
+    // Make sure prompts are visible before blocking on input
+    io.error = io.output.flush().err();
+
+    let mut byte = [0_u8];
+    match io.input.read_exact(&mut byte) {
+        Ok(()) => {
+            let [byte] = byte;
+            byte
+        }
+
+        // Reading past the end of input stores 255, matching the C backend
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => 255,
+
+        Err(e) => {
+            io.error = Some(e);
+            255
+        }
+    }
+}
+
+/// Values shared by every part of the function being built.
+#[derive(Clone, Copy)]
+struct Environment
+{
+    ptr:         Variable,
+    tape_start:  Value,
+    io:          Value,
+    output_byte: FuncRef,
+    input_byte:  FuncRef,
+    out_of_tape: Block,
+    /// Whether the pointer can leave the tape at all, and has to be checked
+    checked:     bool,
+}
+
+/// A [`IRBlock`] compiled to native code with Cranelift.
+///
+/// The tape spans [`TAPE_SIZE`] cells and is padded by the largest offset the
+/// program accesses on either side, so the pointer only has to be checked
+/// when it moves, and not at all if the program can't leave the tape.
+pub struct JitProgram
+{
+    module: Option<JITModule>,
+    entry:  EntryPoint,
+    margin: usize,
+}
+
+impl Drop for JitProgram
+{
+    fn drop(&mut self)
+    {
+        if let Some(module) = self.module.take() {
+            // SAFETY: The entry point never leaves `self`, so nothing can refer to the
+            // compiled code anymore
+            unsafe { module.free_memory() };
+        }
+    }
+}
+
+impl JitProgram
+{
+    pub fn compile(block: &IRBlock) -> Result<Self, RuntimeError>
+    {
+        #[allow(clippy::needless_pass_by_value)]
+        fn err(e: impl ToString) -> RuntimeError
+        {
+            RuntimeError::JitCompilation(e.to_string())
+        }
+
+        let margin = max_offset(block);
+
+        let mut builder = JITBuilder::with_flags(&[("opt_level", "speed")], default_libcall_names()).map_err(err)?;
+        #[allow(clippy::as_conversions, clippy::fn_to_numeric_cast_any)]
+        {
+            builder.symbol("bfbfe_output_byte", output_byte as *const u8);
+            builder.symbol("bfbfe_input_byte", input_byte as *const u8);
+        }
+        let mut module = JITModule::new(builder);
+
+        let ptr_type = module.target_config().pointer_type();
+
+        let mut output_signature = module.make_signature();
+        output_signature.params.push(AbiParam::new(ptr_type));
+        output_signature.params.push(AbiParam::new(types::I8).uext());
+        let output_id = module
+            .declare_function("bfbfe_output_byte", Linkage::Import, &output_signature)
+            .map_err(err)?;
+
+        let mut input_signature = module.make_signature();
+        input_signature.params.push(AbiParam::new(ptr_type));
+        input_signature.returns.push(AbiParam::new(types::I8).uext());
+        let input_id = module
+            .declare_function("bfbfe_input_byte", Linkage::Import, &input_signature)
+            .map_err(err)?;
+
+        let mut ctx = module.make_context();
+        ctx.func.signature.params.push(AbiParam::new(ptr_type));
+        ctx.func.signature.params.push(AbiParam::new(ptr_type));
+        ctx.func.signature.returns.push(AbiParam::new(types::I32));
+        let main_id = module
+            .declare_function("bfbfe_main", Linkage::Local, &ctx.func.signature)
+            .map_err(err)?;
+
+        let mut builder_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut ctx.func, &mut builder_ctx);
+
+        let entry = builder.create_block();
+        builder.append_block_params_for_function_params(entry);
+        builder.switch_to_block(entry);
+        builder.seal_block(entry);
+
+        let &[tape, io] = builder.block_params(entry) else {
+            unreachable!("entry block parameters do not match the signature");
+        };
+
+        let ptr = Variable::new(0);
+        builder.declare_var(ptr, ptr_type);
+        let tape_start = builder.ins().iadd_imm(tape, i64::try_from(margin).map_err(err)?);
+        builder.def_var(ptr, tape_start);
+
+        let env = Environment {
+            ptr,
+            tape_start,
+            io,
+            output_byte: module.declare_func_in_func(output_id, builder.func),
+            input_byte: module.declare_func_in_func(input_id, builder.func),
+            out_of_tape: builder.create_block(),
+            checked: block.footprint().cells().is_none_or(|cells| cells.end > TAPE_SIZE),
+        };
+
+        _compile(block, &mut builder, env)?;
+
+        let status = builder.ins().iconst(types::I32, i64::from(STATUS_OK));
+        builder.ins().return_(&[status]);
+
+        builder.switch_to_block(env.out_of_tape);
+        builder.seal_block(env.out_of_tape);
+        let status = builder
+            .ins()
+            .iconst(types::I32, i64::from(STATUS_POINTER_OUT_OF_BOUNDS));
+        builder.ins().return_(&[status]);
+
+        builder.finalize();
+
+        module.define_function(main_id, &mut ctx).map_err(err)?;
+        module.clear_context(&mut ctx);
+        module.finalize_definitions().map_err(err)?;
+
+        let code = module.get_finalized_function(main_id);
+
+        Ok(Self {
+            module: Some(module),
+            // SAFETY: `bfbfe_main` was declared with exactly this signature
+            entry: unsafe { core::mem::transmute::<*const u8, EntryPoint>(code) },
+            margin,
+        })
+    }
+
+    pub fn run(&self, input: &mut dyn Read, output: &mut dyn Write) -> Result<(), RuntimeError>
+    {
+        let mut tape = vec![0_u8; TAPE_SIZE + 2 * self.margin];
+        let mut io = Io {
+            input,
+            output,
+            error: None,
+        };
+
+        // SAFETY: The compiled program only accesses cells within `margin` of the
+        // pointer and bails out as soon as the pointer leaves the `TAPE_SIZE`
+        // cells in the middle of the tape, unless it never can
+        let status = unsafe { (self.entry)(tape.as_mut_ptr(), core::ptr::addr_of_mut!(io)) };
+
+        if let Some(e) = io.error {
+            return Err(e.into());
+        }
+        io.output.flush()?;
+
+        match status {
+            STATUS_OK => Ok(()),
+            _ => Err(RuntimeError::PointerOutOfBounds),
+        }
+    }
+}
+
+/// Finds the largest distance from the pointer at which `block` accesses the
+/// tape.
+fn max_offset(block: &IRBlock) -> usize
+{
+    block
+        .iter()
+        .map(|instr| match instr {
+            IRInstruction::MutateValue {
+                pos, ..
+            }
+            | IRInstruction::SetTo {
+                pos, ..
+            } => pos.unsigned_abs(),
+            IRInstruction::OutputBytes {
+                poslst,
+            }
+            | IRInstruction::ReadBytes {
+                poslst,
+            } => poslst.iter().map(|pos| pos.unsigned_abs()).max().unwrap_or_default(),
+            IRInstruction::ConditionalBlock(pos, block) | IRInstruction::IfNonZero(pos, block) => {
+                max_offset(block).max(pos.unsigned_abs())
+            }
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+fn _compile(block: &IRBlock, builder: &mut FunctionBuilder, env: Environment) -> Result<(), RuntimeError>
+{
+    macro_rules! offset {
+        ($pos:expr) => {
+            i32::try_from($pos).map_err(|e| RuntimeError::JitCompilation(e.to_string()))?
+        };
+    }
+
+    macro_rules! imm {
+        ($value:expr) => {
+            i64::try_from($value).map_err(|e| RuntimeError::JitCompilation(e.to_string()))?
+        };
+    }
+
+    macro_rules! load {
+        ($pos:expr) => {{
+            let ptr = builder.use_var(env.ptr);
+            builder.ins().load(types::I8, MemFlags::trusted(), ptr, offset!($pos))
+        }};
+    }
+
+    macro_rules! store {
+        ($pos:expr, $value:expr) => {{
+            let ptr = builder.use_var(env.ptr);
+            builder.ins().store(MemFlags::trusted(), $value, ptr, offset!($pos));
+        }};
+    }
+
+    for instr in block.iter() {
+        match instr {
+            // There is no debugger to break into, so debug instructions do nothing
+            IRInstruction::BeginProgram | IRInstruction::EndProgram | IRInstruction::Debug => {}
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                let ptr = builder.use_var(env.ptr);
+                let ptr = builder.ins().iadd_imm(ptr, imm!(*val));
+                builder.def_var(env.ptr, ptr);
+
+                if !env.checked {
+                    continue;
+                }
+
+                // Underflowing the tape wraps around to a large offset, so one unsigned
+                // comparison catches both ends
+                let offset = builder.ins().isub(ptr, env.tape_start);
+                let out_of_tape = builder
+                    .ins()
+                    .icmp_imm(IntCC::UnsignedGreaterThanOrEqual, offset, imm!(TAPE_SIZE));
+
+                let next = builder.create_block();
+                builder.ins().brif(out_of_tape, env.out_of_tape, &[], next, &[]);
+                builder.switch_to_block(next);
+                builder.seal_block(next);
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                let value = load!(*pos);
+                let delta = builder.ins().iconst(types::I8, imm!(val.rem_euclid(256)));
+                let value = builder.ins().iadd(value, delta);
+                store!(*pos, value);
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                let value = builder.ins().iconst(types::I8, imm!(val.rem_euclid(256)));
+                store!(*pos, value);
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    let value = load!(*pos);
+                    builder.ins().call(env.output_byte, &[env.io, value]);
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                for byte in bytes {
+                    let value = builder.ins().iconst(types::I8, i64::from(*byte));
+                    builder.ins().call(env.output_byte, &[env.io, value]);
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    let call = builder.ins().call(env.input_byte, &[env.io]);
+                    let &[value] = builder.inst_results(call) else {
+                        unreachable!("input function signature returns a single value");
+                    };
+                    store!(*pos, value);
+                }
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                let header = builder.create_block();
+                let body = builder.create_block();
+                let exit = builder.create_block();
+
+                builder.ins().jump(header, &[]);
+
+                builder.switch_to_block(header);
+                let value = load!(*pos);
+                builder.ins().brif(value, body, &[], exit, &[]);
+
+                builder.switch_to_block(body);
+                builder.seal_block(body);
+                _compile(block, builder, env)?;
+                builder.ins().jump(header, &[]);
+
+                builder.switch_to_block(exit);
+                builder.seal_block(header);
+                builder.seal_block(exit);
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                let body = builder.create_block();
+                let exit = builder.create_block();
+
+                let value = load!(*pos);
+                builder.ins().brif(value, body, &[], exit, &[]);
+
+                builder.switch_to_block(body);
+                builder.seal_block(body);
+                _compile(block, builder, env)?;
+                builder.ins().jump(exit, &[]);
+
+                builder.switch_to_block(exit);
+                builder.seal_block(exit);
+            }
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                return Err(RuntimeError::UnrecognizedInstruction);
+            }
+        }
+    }
+
+    Ok(())
+}
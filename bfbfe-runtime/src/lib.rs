@@ -0,0 +1,18 @@
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The amount of cells on the tape, matching the C backend.
+pub const TAPE_SIZE: usize = 30000;
+
+pub mod bytecode;
+pub mod cell;
+pub mod debugger;
+mod encoding;
+pub mod interpreter;
+#[cfg(feature = "jit")]
+pub mod jit;
+pub mod limits;
+pub mod profiler;
+pub mod program;
+pub mod runtime_error;
+pub mod snapshot;
+pub mod trace;
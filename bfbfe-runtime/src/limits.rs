@@ -0,0 +1,41 @@
+use core::fmt;
+use core::time::Duration;
+
+/// Bounds on the resources a program may use while being interpreted. Limits
+/// that are `None` are unbounded.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ExecutionLimits
+{
+    /// The amount of operations that may be executed. Every iteration of a
+    /// scanning loop counts as an operation of its own.
+    pub max_steps:  Option<u64>,
+    /// The amount of bytes that may be output.
+    pub max_output: Option<u64>,
+    /// The amount of distinct tape cells that may be read or written.
+    pub max_cells:  Option<usize>,
+    /// The wall-clock time the program may run for.
+    pub timeout:    Option<Duration>,
+}
+
+/// The limit that stopped a program, along with the value it was set to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Limit
+{
+    Steps(u64),
+    Output(u64),
+    Cells(usize),
+    Timeout(Duration),
+}
+
+impl fmt::Display for Limit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::Steps(steps) => write!(f, "step limit of {steps}"),
+            Self::Output(bytes) => write!(f, "output limit of {bytes} bytes"),
+            Self::Cells(cells) => write!(f, "tape limit of {cells} cells"),
+            Self::Timeout(timeout) => write!(f, "time limit of {timeout:?}"),
+        }
+    }
+}
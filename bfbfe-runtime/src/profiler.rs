@@ -0,0 +1,135 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_lang::lexer::Dialect;
+use bfbfe_lang::location::Location;
+use bfbfe_lang::location::Span;
+
+use crate::bytecode::Bytecode;
+use crate::bytecode::LoopIdiom;
+use crate::bytecode::Op;
+use crate::interpreter::Interpreter;
+use crate::runtime_error::RuntimeError;
+
+/// How often a single loop of the source ran.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LoopProfile
+{
+    /// The source from the loop's opening bracket to its closing bracket.
+    pub span:         Span,
+    pub iterations:   u64,
+    /// The amount of instructions executed within the loop, including nested
+    /// loops and the loop's own brackets.
+    pub instructions: u64,
+    /// The idiom the loop is compiled as once optimized, if any.
+    pub idiom:        Option<LoopIdiom>,
+}
+
+/// How often every instruction and loop of a program ran.
+///
+/// Programs are profiled unoptimized, so that every instruction of the source
+/// is counted on its own.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile
+{
+    /// The location of every instruction in the source.
+    pub locations: Vec<Location>,
+    /// How often every instruction was executed, by the same index as
+    /// `locations`.
+    pub counts:    Vec<u64>,
+    /// Every loop in the order it appears in the source.
+    pub loops:     Vec<LoopProfile>,
+}
+
+impl Profile
+{
+    /// Runs `source` to completion while counting every instruction executed.
+    pub fn run(
+        source: &str,
+        dialect: Dialect,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+    ) -> Result<Self, RuntimeError>
+    {
+        let (block, bytecode, locations) = Bytecode::compile_with_locations(source, dialect)?;
+
+        let mut counts = vec![0_u64; bytecode.ops.len()];
+        let mut interpreter = Interpreter::<u8>::new();
+        let mut pc = 0_usize;
+        while let Some(&op) = bytecode.ops.get(pc) {
+            if let Some(count) = counts.get_mut(pc) {
+                *count += 1;
+            }
+            pc = interpreter.step(op, pc, input, output)?;
+        }
+        output.flush()?;
+
+        // Loops are found in source order both ways, so their bodies pair up
+        // with their JumpForwards
+        let mut bodies = Vec::new();
+        collect_loop_bodies(&block, &mut bodies);
+
+        let loops = bytecode
+            .ops
+            .iter()
+            .enumerate()
+            .filter_map(|(start, op)| match op {
+                Op::JumpIfZero(end) => Some((start, end.checked_sub(1)?)),
+                _ => None,
+            })
+            .zip(bodies)
+            .filter_map(|((start, end), body)| {
+                Some(LoopProfile {
+                    span:         Span {
+                        start: *locations.get(start)?,
+                        end:   *locations.get(end)?,
+                    },
+                    iterations:   *counts.get(end)?,
+                    instructions: counts.get(start..=end)?.iter().sum(),
+                    idiom:        LoopIdiom::recognize(&body.clone().optimize()),
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            locations,
+            counts,
+            loops,
+        })
+    }
+
+    /// The amount of instructions executed in total.
+    pub fn instructions(&self) -> u64
+    {
+        self.counts.iter().sum()
+    }
+
+    /// Loops that ran at least once, by the amount of instructions executed
+    /// within them, hottest first.
+    pub fn hottest_loops(&self) -> Vec<&LoopProfile>
+    {
+        let mut loops = self
+            .loops
+            .iter()
+            .filter(|profile| profile.iterations > 0)
+            .collect::<Vec<_>>();
+        loops.sort_by_key(|profile| core::cmp::Reverse(profile.instructions));
+        loops
+    }
+}
+
+/// Collects the body of every loop in `block`, in the order they appear in the
+/// source.
+fn collect_loop_bodies<'a>(block: &'a IRBlock, bodies: &mut Vec<&'a IRBlock>)
+{
+    for instr in block.iter() {
+        if let IRInstruction::ConditionalBlock(_, body) = instr {
+            bodies.push(body);
+            collect_loop_bodies(body, bodies);
+        }
+    }
+}
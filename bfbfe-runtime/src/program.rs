@@ -0,0 +1,123 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_whole_program;
+
+use crate::bytecode::Bytecode;
+use crate::cell::Cell;
+use crate::interpreter::EofPolicy;
+use crate::interpreter::Interpreter;
+use crate::limits::ExecutionLimits;
+use crate::runtime_error::RuntimeError;
+
+/// Options chosen for a single run of a [`Program`]. The width of the cells is
+/// chosen through the type parameter of [`Program::run_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RunOptions
+{
+    pub eof:    EofPolicy,
+    pub limits: ExecutionLimits,
+}
+
+/// The state of the machine after a [`Program`] finished running.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Execution<C = u8>
+{
+    pub tape: Vec<C>,
+    pub ptr:  usize,
+}
+
+/// A Brainfuck program compiled for execution in-process, for embedding BFBFE
+/// in other applications. A compiled program can be run any amount of times,
+/// each against a fresh tape.
+///
+/// ```
+/// use bfbfe_runtime::interpreter::EofPolicy;
+/// use bfbfe_runtime::program::Program;
+/// use bfbfe_runtime::program::RunOptions;
+///
+/// let program = Program::compile(",[.,]")?;
+/// let options = RunOptions {
+///     eof: EofPolicy::Zero,
+///     ..RunOptions::default()
+/// };
+///
+/// let (output, execution) = program.run_bytes_with::<u16>(b"hello", &options)?;
+/// assert_eq!(output, b"hello");
+/// assert_eq!(execution.tape[execution.ptr], 0);
+/// # Ok::<(), bfbfe_runtime::runtime_error::RuntimeError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Program
+{
+    bytecode: Bytecode,
+}
+
+impl Program
+{
+    /// Tokenizes, instructionizes and optimizes `source`, the same way the
+    /// CLI does before handing a program to a backend.
+    pub fn compile(source: &str) -> Result<Self, RuntimeError>
+    {
+        let tokens = tokenize_whole_program(&source.chars().collect::<Vec<_>>());
+        Self::from_ir(&instructionize(&tokens)?.optimize())
+    }
+
+    pub fn from_ir(block: &IRBlock) -> Result<Self, RuntimeError>
+    {
+        Ok(Self {
+            bytecode: Bytecode::compile(block)?,
+        })
+    }
+
+    #[inline]
+    pub const fn bytecode(&self) -> &Bytecode
+    {
+        &self.bytecode
+    }
+
+    /// Runs the program with 8-bit cells and the default [`RunOptions`].
+    pub fn run(&self, input: &mut impl Read, output: &mut impl Write) -> Result<Execution, RuntimeError>
+    {
+        self.run_with::<u8>(input, output, &RunOptions::default())
+    }
+
+    pub fn run_with<C: Cell>(
+        &self,
+        input: &mut impl Read,
+        output: &mut impl Write,
+        options: &RunOptions,
+    ) -> Result<Execution<C>, RuntimeError>
+    {
+        let mut interpreter = Interpreter::<C>::new().with_eof_policy(options.eof);
+        interpreter.run_with_limits(&self.bytecode, input, output, &options.limits)?;
+
+        let (tape, ptr) = interpreter.into_parts();
+        Ok(Execution {
+            tape,
+            ptr,
+        })
+    }
+
+    /// Runs the program with 8-bit cells and the default [`RunOptions`],
+    /// reading from and writing to memory.
+    pub fn run_bytes(&self, input: &[u8]) -> Result<(Vec<u8>, Execution), RuntimeError>
+    {
+        self.run_bytes_with::<u8>(input, &RunOptions::default())
+    }
+
+    pub fn run_bytes_with<C: Cell>(
+        &self,
+        mut input: &[u8],
+        options: &RunOptions,
+    ) -> Result<(Vec<u8>, Execution<C>), RuntimeError>
+    {
+        let mut output = Vec::new();
+        let execution = self.run_with(&mut input, &mut output, options)?;
+        Ok((output, execution))
+    }
+}
@@ -0,0 +1,33 @@
+#![allow(clippy::std_instead_of_core)]
+
+use bfbfe_lang::instructionize::InstructionizingError;
+use thiserror::Error;
+
+use crate::limits::Limit;
+
+#[derive(Debug, Error)]
+pub enum RuntimeError
+{
+    #[error("failed to instructionize program: {0}")]
+    Instructionizing(#[from] InstructionizingError),
+    #[error("pointer moved outside of the tape")]
+    PointerOutOfBounds,
+    #[error("{limit} exceeded at operation {pc} with the pointer at cell {ptr}")]
+    LimitExceeded
+    {
+        limit: Limit, pc: usize, ptr: usize
+    },
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unrecognized instruction")]
+    UnrecognizedInstruction,
+    #[error("JIT compilation failed: {0}")]
+    JitCompilation(String),
+    #[error("malformed file: {0}")]
+    MalformedFile(&'static str),
+    #[error("the program diverged from its trace at step {step}")]
+    TraceMismatch
+    {
+        step: usize
+    },
+}
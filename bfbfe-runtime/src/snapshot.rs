@@ -0,0 +1,256 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::Read;
+use std::io::Write;
+
+use crate::bytecode::Bytecode;
+use crate::bytecode::Op;
+use crate::encoding::read_array;
+use crate::encoding::read_bytes;
+use crate::encoding::read_signed;
+use crate::encoding::read_varint;
+use crate::encoding::write_bytes;
+use crate::encoding::write_signed;
+use crate::encoding::write_varint;
+use crate::interpreter::Interpreter;
+use crate::runtime_error::RuntimeError;
+use crate::TAPE_SIZE;
+
+/// Identifies snapshot files, followed by the version of the format.
+const MAGIC: &[u8; 8] = b"BFBFESNP";
+const VERSION: u8 = 2;
+
+// Every operation starts with one of these tags
+const OP_TRAVERSE: u8 = 0;
+const OP_MUTATE: u8 = 1;
+const OP_SET: u8 = 2;
+const OP_MUL_ADD: u8 = 3;
+const OP_OUTPUT: u8 = 4;
+const OP_READ: u8 = 5;
+const OP_SCAN: u8 = 6;
+const OP_JUMP_IF_ZERO: u8 = 7;
+const OP_JUMP_IF_NON_ZERO: u8 = 8;
+const OP_BREAKPOINT: u8 = 9;
+const OP_OUTPUT_BYTE: u8 = 10;
+
+/// The complete state of a program being interpreted with 8-bit cells, so that
+/// it can be stopped and resumed later, possibly on another machine.
+///
+/// # Format
+///
+/// A snapshot starts with `BFBFESNP` and a version byte, followed by the
+/// bytecode as a count of operations and the operations themselves, each
+/// tagged by its first byte. Then follow the index of the next operation, the
+/// pointer, the tape without its trailing zero cells, how much input was
+/// consumed and the pending output. Integers are LEB128 encoded, and constants
+/// of operations are stored as four little-endian bytes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot
+{
+    pub bytecode: Bytecode,
+    pub tape:     Vec<u8>,
+    pub ptr:      usize,
+    /// The index of the operation to execute next.
+    pub pc:       usize,
+    /// How many bytes of input the program has consumed, which are skipped
+    /// when it is resumed on the same input.
+    pub consumed: usize,
+    /// Output that the program produced, but that has yet to be written.
+    pub output:   Vec<u8>,
+}
+
+impl Snapshot
+{
+    /// Captures the state of `interpreter` about to execute the operation at
+    /// index `pc` of `bytecode`.
+    pub fn capture(bytecode: &Bytecode, interpreter: &Interpreter, pc: usize) -> Self
+    {
+        Self {
+            bytecode: bytecode.clone(),
+            tape: interpreter.tape().to_vec(),
+            ptr: interpreter.ptr(),
+            pc,
+            consumed: 0,
+            output: Vec::new(),
+        }
+    }
+
+    /// Creates an interpreter in the state the snapshot was captured in.
+    pub fn restore(&self) -> Interpreter
+    {
+        Interpreter::from_parts(self.tape.clone(), self.ptr)
+    }
+
+    pub fn write(&self, writer: &mut dyn Write) -> Result<(), RuntimeError>
+    {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        write_varint(writer, self.bytecode.ops.len())?;
+        for &op in &self.bytecode.ops {
+            write_op(writer, op)?;
+        }
+
+        write_varint(writer, self.pc)?;
+        write_varint(writer, self.ptr)?;
+
+        let used = self.tape.iter().rposition(|&cell| cell != 0).map_or(0, |last| last + 1);
+        write_bytes(writer, self.tape.get(..used).unwrap_or_default())?;
+
+        write_varint(writer, self.consumed)?;
+        write_bytes(writer, &self.output)?;
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn read(reader: &mut dyn Read) -> Result<Self, RuntimeError>
+    {
+        let mut magic = [0_u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(RuntimeError::MalformedFile("not a snapshot file"));
+        }
+
+        let [version] = read_array(reader)?;
+        if version != VERSION {
+            return Err(RuntimeError::MalformedFile("unsupported version"));
+        }
+
+        let len = read_varint(reader)?;
+        let ops = (0..len).map(|_| read_op(reader)).collect::<Result<Vec<_>, _>>()?;
+
+        let pc = read_varint(reader)?;
+        let ptr = read_varint(reader)?;
+
+        let mut tape = read_bytes(reader)?;
+        if tape.len() > TAPE_SIZE {
+            return Err(RuntimeError::MalformedFile("tape is too long"));
+        }
+        tape.resize(TAPE_SIZE, 0);
+
+        let consumed = read_varint(reader)?;
+        let output = read_bytes(reader)?;
+
+        Ok(Self {
+            bytecode: Bytecode {
+                ops,
+            },
+            tape,
+            ptr,
+            pc,
+            consumed,
+            output,
+        })
+    }
+}
+
+fn write_op(writer: &mut dyn Write, op: Op) -> Result<(), RuntimeError>
+{
+    match op {
+        Op::Traverse(val) => {
+            writer.write_all(&[OP_TRAVERSE])?;
+            write_signed(writer, val)?;
+        }
+
+        Op::Mutate {
+            pos,
+            val,
+        } => {
+            writer.write_all(&[OP_MUTATE])?;
+            write_signed(writer, pos)?;
+            writer.write_all(&val.to_le_bytes())?;
+        }
+
+        Op::Set {
+            pos,
+            val,
+        } => {
+            writer.write_all(&[OP_SET])?;
+            write_signed(writer, pos)?;
+            writer.write_all(&val.to_le_bytes())?;
+        }
+
+        Op::MulAdd {
+            pos,
+            factor,
+        } => {
+            writer.write_all(&[OP_MUL_ADD])?;
+            write_signed(writer, pos)?;
+            writer.write_all(&factor.to_le_bytes())?;
+        }
+
+        Op::Output {
+            pos,
+        } => {
+            writer.write_all(&[OP_OUTPUT])?;
+            write_signed(writer, pos)?;
+        }
+
+        Op::OutputByte(byte) => {
+            writer.write_all(&[OP_OUTPUT_BYTE, byte])?;
+        }
+
+        Op::Read {
+            pos,
+        } => {
+            writer.write_all(&[OP_READ])?;
+            write_signed(writer, pos)?;
+        }
+
+        Op::Scan(val) => {
+            writer.write_all(&[OP_SCAN])?;
+            write_signed(writer, val)?;
+        }
+
+        Op::JumpIfZero(target) => {
+            writer.write_all(&[OP_JUMP_IF_ZERO])?;
+            write_varint(writer, target)?;
+        }
+
+        Op::JumpIfNonZero(target) => {
+            writer.write_all(&[OP_JUMP_IF_NON_ZERO])?;
+            write_varint(writer, target)?;
+        }
+
+        Op::Breakpoint => {
+            writer.write_all(&[OP_BREAKPOINT])?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_op(reader: &mut dyn Read) -> Result<Op, RuntimeError>
+{
+    Ok(match read_array(reader)? {
+        [OP_TRAVERSE] => Op::Traverse(read_signed(reader)?),
+        [OP_MUTATE] => Op::Mutate {
+            pos: read_signed(reader)?,
+            val: u32::from_le_bytes(read_array(reader)?),
+        },
+        [OP_SET] => Op::Set {
+            pos: read_signed(reader)?,
+            val: u32::from_le_bytes(read_array(reader)?),
+        },
+        [OP_MUL_ADD] => Op::MulAdd {
+            pos:    read_signed(reader)?,
+            factor: u32::from_le_bytes(read_array(reader)?),
+        },
+        [OP_OUTPUT] => Op::Output {
+            pos: read_signed(reader)?,
+        },
+        [OP_OUTPUT_BYTE] => Op::OutputByte(u8::from_le_bytes(read_array(reader)?)),
+        [OP_READ] => Op::Read {
+            pos: read_signed(reader)?,
+        },
+        [OP_SCAN] => Op::Scan(read_signed(reader)?),
+        [OP_JUMP_IF_ZERO] => Op::JumpIfZero(read_varint(reader)?),
+        [OP_JUMP_IF_NON_ZERO] => Op::JumpIfNonZero(read_varint(reader)?),
+        [OP_BREAKPOINT] => Op::Breakpoint,
+        _ => {
+            return Err(RuntimeError::MalformedFile("unknown operation"));
+        }
+    })
+}
@@ -0,0 +1,255 @@
+#![allow(clippy::std_instead_of_core)]
+
+use std::io::Read;
+use std::io::Write;
+
+use bfbfe_lang::lexer::Dialect;
+
+use crate::bytecode::Bytecode;
+use crate::bytecode::Op;
+use crate::debugger::Debugger;
+use crate::encoding::read_array;
+use crate::encoding::read_bytes;
+use crate::encoding::read_varint;
+use crate::encoding::write_bytes;
+use crate::encoding::write_varint;
+use crate::interpreter::Interpreter;
+use crate::runtime_error::RuntimeError;
+
+/// Identifies trace files, followed by the version of the format.
+const MAGIC: &[u8; 8] = b"BFBFETRC";
+const VERSION: u8 = 1;
+
+/// Set in the flags of the header if the program was tokenized with
+/// [`Dialect::debug`].
+const FLAG_DEBUG: u8 = 0b1;
+
+// Every record starts with one of these tags
+const TAG_STEP: u8 = 0;
+const TAG_INPUT: u8 = 1;
+const TAG_END: u8 = 2;
+
+/// A single executed instruction of a trace.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TraceStep
+{
+    /// The index of the instruction in the unoptimized program.
+    pub pc:   usize,
+    /// The pointer after executing the instruction.
+    pub ptr:  usize,
+    /// The value of the cell the instruction affected or tested, after
+    /// executing it.
+    pub cell: u8,
+}
+
+impl TraceStep
+{
+    fn observe(op: Op, pc: usize, tape: &[u8], ptr: usize) -> Self
+    {
+        let pos = match op {
+            Op::Mutate {
+                pos, ..
+            }
+            | Op::Set {
+                pos, ..
+            }
+            | Op::MulAdd {
+                pos, ..
+            }
+            | Op::Output {
+                pos,
+            }
+            | Op::Read {
+                pos,
+            } => pos,
+            _ => 0,
+        };
+
+        let cell = ptr.checked_add_signed(pos).and_then(|index| tape.get(index)).copied();
+
+        Self {
+            pc,
+            ptr,
+            cell: cell.unwrap_or_default(),
+        }
+    }
+}
+
+/// A recording of every instruction a program executed, along with the input
+/// it consumed, so that the run can be replayed exactly.
+///
+/// Traces are recorded against the unoptimized program, which is stored in
+/// the trace itself.
+///
+/// # Format
+///
+/// A trace starts with `BFBFETRC`, a version byte, a byte of flags and the
+/// source of the program as a length-prefixed string. It is followed by
+/// records tagged by their first byte: a step, holding the index of the
+/// instruction and the pointer as LEB128 integers followed by the value of
+/// the cell; a byte of input; or the end of the trace.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace
+{
+    pub source:  String,
+    pub dialect: Dialect,
+    pub steps:   Vec<TraceStep>,
+    /// Every byte of input that was consumed, in order.
+    pub input:   Vec<u8>,
+}
+
+impl Trace
+{
+    /// Runs `source` to completion, writing a trace to `trace` as it goes. The
+    /// trace is finished even if the program fails, in which case the error
+    /// is returned afterwards.
+    pub fn record(
+        source: &str,
+        dialect: Dialect,
+        input: &mut dyn Read,
+        output: &mut dyn Write,
+        trace: &mut dyn Write,
+    ) -> Result<u64, RuntimeError>
+    {
+        let (_, bytecode, _) = Bytecode::compile_with_locations(source, dialect)?;
+
+        trace.write_all(MAGIC)?;
+        trace.write_all(&[VERSION, if dialect.debug { FLAG_DEBUG } else { 0 }])?;
+        write_bytes(trace, source.as_bytes())?;
+
+        let mut input = RecordingReader {
+            inner:    input,
+            consumed: Vec::new(),
+        };
+        let mut interpreter = Interpreter::<u8>::new();
+        let mut pc = 0_usize;
+        let mut steps = 0_u64;
+
+        let result = loop {
+            let Some(&op) = bytecode.ops.get(pc) else {
+                break output.flush().map_err(RuntimeError::from);
+            };
+
+            let next = match interpreter.step(op, pc, &mut input, output) {
+                Ok(next) => next,
+                Err(e) => break Err(e),
+            };
+
+            let step = TraceStep::observe(op, pc, interpreter.tape(), interpreter.ptr());
+            trace.write_all(&[TAG_STEP])?;
+            write_varint(trace, step.pc)?;
+            write_varint(trace, step.ptr)?;
+            trace.write_all(&[step.cell])?;
+
+            for byte in input.consumed.drain(..) {
+                trace.write_all(&[TAG_INPUT, byte])?;
+            }
+
+            pc = next;
+            steps += 1;
+        };
+
+        trace.write_all(&[TAG_END])?;
+        trace.flush()?;
+
+        result.map(|()| steps)
+    }
+
+    pub fn read(reader: &mut dyn Read) -> Result<Self, RuntimeError>
+    {
+        let mut magic = [0_u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(RuntimeError::MalformedFile("not a trace file"));
+        }
+
+        let [version, flags] = read_array(reader)?;
+        if version != VERSION {
+            return Err(RuntimeError::MalformedFile("unsupported version"));
+        }
+
+        let source = String::from_utf8(read_bytes(reader)?)
+            .map_err(|_| RuntimeError::MalformedFile("source is not valid UTF-8"))?;
+
+        let mut steps = Vec::new();
+        let mut input = Vec::new();
+        loop {
+            match read_array(reader)? {
+                [TAG_STEP] => {
+                    let pc = read_varint(reader)?;
+                    let ptr = read_varint(reader)?;
+                    let [cell] = read_array(reader)?;
+                    steps.push(TraceStep {
+                        pc,
+                        ptr,
+                        cell,
+                    });
+                }
+
+                [TAG_INPUT] => {
+                    let [byte] = read_array(reader)?;
+                    input.push(byte);
+                }
+
+                [TAG_END] => break,
+
+                _ => {
+                    return Err(RuntimeError::MalformedFile("unknown record"));
+                }
+            }
+        }
+
+        Ok(Self {
+            source,
+            dialect: Dialect {
+                debug: flags & FLAG_DEBUG != 0,
+            },
+            steps,
+            input,
+        })
+    }
+
+    /// Replays the first `steps` steps of the trace in a [`Debugger`], checking
+    /// every step against the trace. `input` should read from the recorded
+    /// [`input`](Self::input), and keeps being used by the debugger afterwards.
+    pub fn seek(&self, steps: usize, input: &mut dyn Read, output: &mut dyn Write) -> Result<Debugger, RuntimeError>
+    {
+        let mut debugger = Debugger::new(&self.source, self.dialect)?;
+
+        for (step, expected) in self.steps.iter().take(steps).enumerate() {
+            let pc = debugger.pc();
+            let Some(op) = debugger.op() else {
+                return Err(RuntimeError::TraceMismatch {
+                    step,
+                });
+            };
+
+            debugger.step(1, input, output)?;
+
+            if TraceStep::observe(op, pc, debugger.tape(), debugger.ptr()) != *expected {
+                return Err(RuntimeError::TraceMismatch {
+                    step,
+                });
+            }
+        }
+
+        Ok(debugger)
+    }
+}
+
+/// Passes reads through, keeping the bytes read until they are drained.
+struct RecordingReader<'a>
+{
+    inner:    &'a mut dyn Read,
+    consumed: Vec<u8>,
+}
+
+impl Read for RecordingReader<'_>
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>
+    {
+        let read = self.inner.read(buf)?;
+        self.consumed.extend(buf.iter().take(read));
+        Ok(read)
+    }
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::std_instead_of_core)]
+
+//! Loads the programs of the corpus in `tests/corpus`, which every program is
+//! read from as `name.b`, along with the files recorded beside it.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_whole_program;
+
+pub struct Case
+{
+    pub name:  String,
+    /// The path of the program without its extension, which the files
+    /// recorded for it are found beside.
+    pub path:  PathBuf,
+    pub block: IRBlock,
+}
+
+pub fn corpus() -> Vec<Case>
+{
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("corpus");
+
+    let mut cases = fs::read_dir(&dir)
+        .expect("failed to read the corpus")
+        .map(|entry| entry.expect("failed to read the corpus").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "b"))
+        .map(|path| {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+            let source = fs::read_to_string(&path).expect("failed to read a program of the corpus");
+            let tokens = tokenize_whole_program(&source.chars().collect::<Vec<_>>());
+
+            Case {
+                block: instructionize(&tokens).unwrap_or_else(|e| panic!("{name}.b is malformed: {e}")),
+                path: path.with_extension(""),
+                name,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    assert!(!cases.is_empty(), "the corpus is empty");
+    cases
+}
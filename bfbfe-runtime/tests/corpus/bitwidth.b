@@ -0,0 +1,23 @@
+Reports how wide the cells are by checking whether 256 and then 65536 wrap
+around to zero
+
+Build 256 in the second cell
+++++++++[>++++++++<-]>[<++++>-]
++<[>-<
+    Not zero so multiply by 256 again to get 65536
+    [>++++<-]>[<++++++++>-]<[>++++++++<-]
+    +>[>
+        Print 32
+        ++++++++++[>+++++<-]>+.-.[-]<
+    <[-]<->] <[>>
+        Print 16
+        +++++++[>+++++++<-]>.+++++.[-]<
+<<-]] >[>
+    Print 8
+    ++++++++[>+++++++<-]>.[-]<
+<-]<
+Print bit cells and a newline
++++++++++++[>+++>+++++++++>+++++++++>+<<<<-]>-.>-.+++++++.+++++++++++.<.
+>>.++.+++++++..<-.>>-.
+Clear every cell that was used
+[[-]<]
@@ -0,0 +1,105 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 30000
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    tape[ptr] = 256;
+    tape[ptr + 1] = 1;
+    if (tape[ptr] != 0) {
+        tape[ptr + 1] -= 1;
+        while (tape[ptr] != 0) {
+            tape[ptr] -= 1;
+            tape[ptr + 1] += 4;
+        }
+        while (tape[ptr + 1] != 0) {
+            tape[ptr] += 8;
+            tape[ptr + 1] -= 1;
+        }
+        while (tape[ptr] != 0) {
+            tape[ptr] -= 1;
+            tape[ptr + 1] += 8;
+        }
+        tape[ptr] = 1;
+        if (tape[ptr + 1] != 0) {
+            tape[ptr + 2] += 10;
+            while (tape[ptr + 2] != 0) {
+                tape[ptr + 2] -= 1;
+                tape[ptr + 3] += 5;
+            }
+            tape[ptr + 3] += 1;
+            ptr += 2;
+            printf("%c", tape[ptr + 1]);
+            tape[ptr + 1] -= 1;
+            printf("%c", tape[ptr + 1]);
+            tape[ptr - 1] = 0;
+            tape[ptr + 1] = 0;
+            tape[ptr - 2] -= 1;
+            ptr -= 2;
+        }
+        while (tape[ptr] != 0) {
+            tape[ptr + 2] += 7;
+            while (tape[ptr + 2] != 0) {
+                tape[ptr + 2] -= 1;
+                tape[ptr + 3] += 7;
+            }
+            ptr += 3;
+            printf("%c", tape[ptr]);
+            tape[ptr] += 5;
+            printf("%c", tape[ptr]);
+            tape[ptr] = 0;
+            tape[ptr - 3] -= 1;
+            ptr -= 3;
+        }
+    }
+    while (tape[ptr + 1] != 0) {
+        tape[ptr + 2] += 8;
+        while (tape[ptr + 2] != 0) {
+            tape[ptr + 2] -= 1;
+            tape[ptr + 3] += 7;
+        }
+        ptr += 2;
+        printf("%c", tape[ptr + 1]);
+        tape[ptr + 1] = 0;
+        tape[ptr - 1] -= 1;
+        ptr -= 2;
+    }
+    tape[ptr] = 0;
+    tape[ptr + 1] = 32;
+    tape[ptr + 2] += 99;
+    tape[ptr + 3] += 99;
+    ptr += 1;
+    fwrite(" ", 1, 1, stdout);
+    tape[ptr + 1] -= 1;
+    ptr += 1;
+    printf("%c", tape[ptr]);
+    tape[ptr] += 7;
+    printf("%c", tape[ptr]);
+    tape[ptr] += 11;
+    printf("%c", tape[ptr]);
+    ptr -= 1;
+    fwrite(" ", 1, 1, stdout);
+    ptr += 2;
+    printf("%c", tape[ptr]);
+    tape[ptr] += 2;
+    printf("%c", tape[ptr]);
+    tape[ptr] += 7;
+    printf("%c%c", tape[ptr], tape[ptr]);
+    tape[ptr - 1] -= 1;
+    ptr -= 1;
+    printf("%c", tape[ptr]);
+    tape[ptr + 2] = 10;
+    ptr += 2;
+    fwrite("\012", 1, 1, stdout);
+    while (tape[ptr] != 0) {
+        tape[ptr] = 0;
+        ptr -= 1;
+    }
+
+    return 0;
+}
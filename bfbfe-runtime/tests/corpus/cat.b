@@ -0,0 +1,3 @@
+Copies input to output until the end of input which reads as 255
+
+,+[-.,+]
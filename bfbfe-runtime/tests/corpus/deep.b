@@ -0,0 +1,7 @@
+Eight loops nested inside one another each multiply by three so the
+innermost cell ends up at 3 to the 8th modulo 256 which is then turned into
+the letter A
+
++++[>+++[>+++[>+++[>+++[>+++[>+++[>+++[>+<-]<-]<-]<-]<-]<-]<-]<-]
+>>>>>>>>------------------------------------------------------------------------------------------------.
+>++++++++++.
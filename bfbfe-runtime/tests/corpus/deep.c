@@ -0,0 +1,18 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 10
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("A\012", 1, 2, stdout);
+    tape[ptr + 8] = 6465;
+    tape[ptr + 9] = 10;
+    ptr += 9;
+
+    return 0;
+}
@@ -0,0 +1,102 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 30000
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    tape[ptr] = getchar();
+    tape[ptr] += 1;
+    while (tape[ptr] != 0) {
+        tape[ptr] -= 1;
+        tape[ptr + 2] += 10;
+        while (tape[ptr] != 0) {
+            tape[ptr] -= 1;
+            tape[ptr + 1] += 1;
+            tape[ptr + 2] -= 1;
+            ptr += 2;
+            while (tape[ptr] != 0) {
+                tape[ptr + 1] += 1;
+                ptr += 3;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] += 1;
+                while (tape[ptr] != 0) {
+                    tape[ptr - 1] += 1;
+                    tape[ptr] -= 1;
+                }
+                tape[ptr + 1] += 1;
+                ptr += 3;
+            }
+            ptr -= 6;
+        }
+        tape[ptr + 2] = 0;
+        tape[ptr + 5] += 10;
+        ptr += 4;
+        while (tape[ptr] != 0) {
+            tape[ptr] -= 1;
+            tape[ptr + 1] -= 1;
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr + 1] += 1;
+                ptr += 3;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] += 1;
+                while (tape[ptr] != 0) {
+                    tape[ptr - 1] += 1;
+                    tape[ptr] -= 1;
+                }
+                tape[ptr + 1] += 1;
+                ptr += 3;
+            }
+            ptr -= 5;
+        }
+        tape[ptr + 1] = 0;
+        if (tape[ptr + 3] != 0) {
+            tape[ptr + 4] += 6;
+            while (tape[ptr + 4] != 0) {
+                tape[ptr + 3] += 8;
+                tape[ptr + 4] -= 1;
+            }
+            printf("%c", tape[ptr + 3]);
+            tape[ptr + 3] = 0;
+            tape[ptr + 1] += 1;
+            tape[ptr + 2] += 1;
+        }
+        if (tape[ptr + 2] != 0) {
+            while (tape[ptr + 1] != 0) {
+                tape[ptr + 1] -= 1;
+                tape[ptr + 2] -= 1;
+            }
+            tape[ptr + 1] = 0;
+            tape[ptr + 2] += 48;
+            printf("%c", tape[ptr + 2]);
+            tape[ptr + 2] = 0;
+        }
+        tape[ptr] = 0;
+        tape[ptr - 1] += 48;
+        ptr -= 1;
+        printf("%c", tape[ptr]);
+        tape[ptr] = 0;
+        while (tape[ptr - 2] != 0) {
+            tape[ptr - 3] += 1;
+            tape[ptr - 2] -= 1;
+        }
+        tape[ptr - 2] = 10;
+        ptr -= 2;
+        fwrite("\012", 1, 1, stdout);
+        tape[ptr] = 0;
+        ptr -= 1;
+        tape[ptr] = getchar();
+        tape[ptr] += 1;
+    }
+
+    return 0;
+}
@@ -0,0 +1,4 @@
+Reads two bytes more than there are in the input with each read past its
+end storing 255
+
+,.,.,.,.
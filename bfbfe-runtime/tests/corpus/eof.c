@@ -0,0 +1,22 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 1
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    tape[ptr] = getchar();
+    printf("%c", tape[ptr]);
+    tape[ptr] = getchar();
+    printf("%c", tape[ptr]);
+    tape[ptr] = getchar();
+    printf("%c", tape[ptr]);
+    tape[ptr] = getchar();
+    printf("%c", tape[ptr]);
+
+    return 0;
+}
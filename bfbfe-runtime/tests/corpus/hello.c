@@ -0,0 +1,21 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 7
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("Hello World!\012", 1, 13, stdout);
+    tape[ptr + 2] = 72;
+    tape[ptr + 3] = 100;
+    tape[ptr + 4] = 87;
+    tape[ptr + 5] = 33;
+    tape[ptr + 6] = 10;
+    ptr += 6;
+
+    return 0;
+}
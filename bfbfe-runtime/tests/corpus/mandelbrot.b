@@ -0,0 +1,107 @@
+Draws the Mandelbrot set in ASCII
+(written for this corpus; not Erik Bosman's mandelbrot)
+
+Points from minus two to seven sixteenths along the real axis and from minus
+one to one along the imaginary axis are iterated at most twelve times in fixed
+point with a scale of sixteen; each character shows how many iterations a point
+took to escape
+
+>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>
+>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]++++++++++>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]+<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<[-]++++++++++++++++<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<[-]+++++++++++++++++[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]+<<<<<<<<<<<<<<<[-]+++++++++++++++++++++++++++++++
++<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]++++++++++++++++
+++++++++++++++++++++++++[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]<<<[-]>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]<<<<<<<
+<<[-]<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>[-]+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<[-]++++++++++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<[-]++++++++>>>>>>[-]<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>[->-]>[>>>>>[-]+<<<<<->]<
++<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[>>
+>>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<-]>[<<<<<<<[-]++++++++>>>>>>[-]<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>[->-]>[>>>>>[-]+<<<<<->]<+<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[>>>>>>>>>>>>>
+>>>>>[-]<<<<<<<<<<<<<<<<<-]>[<<<<<<<<<<<<<[-]>>>[-]<<<<<<<<<<<<<<<<<<<<<[-]<<<<<
+<[->>>>>>+<<<<+<<]>>[-<<+>>]<<<<<<<<<<<<<<[-]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>>>+<<
+]>>[-<<+>>]>[->>+>>>>[->>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>[-<<+>>]<<<<<<<<
+]>>[-<<+>>]<<<<<<<<<<<[-]++++<<<<<<[-]++++>>>>>>>>>>>>>>>[->>+<<<<<<<<<<<<<<[->>
++>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+->]<+<<<<]>>[-<<+>>]>>>>>>>>>>]>>[-<<+>>]<<<<<[->>+>>>>>>>[->>+<<<<<<<<<<<<<<<<<
+-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<->
+]<+>>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<]>>[-<<+>>]<<[->>+<<<<<<<<<<<[->>+<<<<<-[
+>-]>[<++++>>>>>>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<->]<+<<<<<<->]<+>>]>>[-<<+>>]>>>>>>>]>>[-<<+>>]>>>>>>>[-]<<<[->>>+
+<+<<]>>[-<<+>>]<<<<<<<<<<<<<<<<<[-]>>>>>>[-<<<<<<+>>>>>>>>+<<]>>[-<<+>>]>>>>>>>[
+->>+>[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]<<<<<]>>[-<<+>>]<<
+<<<<<<<<<<<<[-]++++<<<<<<[-]++++>>>>>>>>>>>>>>>>>>[->>+<<<<<<<<<<<<<<<<<[->>+>-[
+>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<->]<+<<<<]>>[-<<+>>]>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<[->>+>>>>>>>>>>[->>+<<<
+<<<<<<<<<<<<<<-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<->]<+>>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<]>>[-<<+>>]<<[->>+
+<<<<<<<<[->>+<<<<<-[>-]>[<++++>>>>>>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<->]<+<<<<<<->]<+>>]>>[-<<+>>]>>>>]>>[-<<
++>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]+++++++++++++++++++++++++++++++++++++++
++++++++++++++++++++++++++>>>>>>[-]<<<<<<<<<<<<[->>+>>>>[->-]>[>>>>>[-]+<<<<<->]<
++<<<<<<<]>>[-<<+>>]>[->>+>[->-]>[>>>>>[-]+<<<<<->]<+<<<<]>>[-<<+>>]>>>>>>>[>>>>>
+>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<-]>[>>>>>>>>[-]<<<[<<<[>-]>[>>>>>+<<<<<->]<+>>>
+-]>[<<<<[>>>>>>+<<<<<-]>[->]<+>>>->]<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]<<<[-]>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]<<<[->>>+
+<+<<]>>[-<<+>>]<<<<<<<<<<<<<<<<<[-]>>>>>>[-<<<<<<+>>>>>>>>+<<]>>[-<<+>>]>>>>[->>
++>>>>[->>+>>>>++<<<<<<]>>[-<<+>>]<<<<<<<<]>>[-<<+>>]<<<<<<<<<<<[-]++++<<<<<<[-]+
++++>>>>>>>>>>>>>>>[->>+<<<<<<<<<<<<<<[->>+>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>++<<
+<<<<<<<<<<<<<<<<<<->]<+<<<<]>>[-<<+>>]>>>>>>>>>>]>>[-<<+>>]<<<<<[->>+>>>>>>>[->>
++<<<<<<<<<<<<<<<<<-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>++<<<<<<<<<<<<<<<<<<<<->]<+>>
+>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<]>>[-<<+>>]<<[->>+<<<<<<<<<<<[->>+<<<<<-[>-]>[
+<++++>>>>>>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>++<<<<<<<<<<<<<<<<<<<<->]<+<<<<<<->]
+<+>>]>>[-<<+>>]>>>>>>>]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+-]>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]<<<[->>>+<+<<]>>[-<<+>>]<<<<<<<<<
+<<<<<<<<[-]>>>>>>[-<<<<<<+>>>>>>>>+<<]>>[-<<+>>]>>>>[->>+>>>>[->>+>>>>>>>++<<<<<
+<<<<]>>[-<<+>>]<<<<<<<<]>>[-<<+>>]<<<<<<<<<<<[-]++++<<<<<<[-]++++>>>>>>>>>>>>>>>
+[->>+<<<<<<<<<<<<<<[->>+>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>++<<<<<<<<<<<<<<<<<
+<<<<<<->]<+<<<<]>>[-<<+>>]>>>>>>>>>>]>>[-<<+>>]<<<<<[->>+>>>>>>>[->>+<<<<<<<<<<<
+<<<<<<-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>++<<<<<<<<<<<<<<<<<<<<<<<->]<+>>>>>>>>
+>>>>>>]>>[-<<+>>]<<<<<<<<<<<]>>[-<<+>>]<<[->>+<<<<<<<<<<<[->>+<<<<<-[>-]>[<++++>
+>>>>>-[>-]>[<++++>>>>>>>>>>>>>>>>>>>>>>>>++<<<<<<<<<<<<<<<<<<<<<<<->]<+<<<<<<->]
+<+>>]>>[-<<+>>]>>>>>>>]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+->]<+>>[<<<<<<<<<<<<<<<[->>+<<<<<<<<+>>>>>>]>>[-<<+>>]>>>>>>>>>>>>>>-]>[<<<<<<<<
+<<<<<<<<[->>+<<<<<<<<<<<+>>>>>>>>>]>>[-<<+>>]>>>>>>>>>>>>>>->]<+>>[<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>+<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>-]>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>+<<<<<<]>>[-<
+<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>->]<+<<<<<<<<<<[-]<<<<<<<<<<<<<<<[
+-<<<[->-]>[<<<<+>>>>->]<+>>]<<<<<<[>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<-]>
+[->]<+<[->>>+<<<]<<<<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>[-]++++>>>>>>[-<<<<<<
+-[>-]>[<++++<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>->]<+>>>>>]<<<<<<<<<<<<<<<<<<<
+<<<<<<<<[-]++++>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<->>>>>>>>>>>>>>>>>>>>
+>]>>>>>>>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>[->-]>[>>>>>+<<<<<->]<
++<<<<]>>>>>>>>>[>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<-]>[->]<+<[-<<<<<<+>>>>>>]<<
+<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>[-]++++<<<[->>>-[>-]>[<++++<<<<<<<<<<<<<<<+>>>
+>>>>>>>>>>>>>->]<+<<<<]<<<<<<<<<<<<<<<<<<<<<[-]++++>>>>>>>>>>>>>>>>>>>>>>>>[-<<<
+<<<<<<<<<<<<<<<<<<<<<->>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<+>>>-[>-]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<->]<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>->]]]<+>>>>>>>>>>>>
+>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<[-]++++++++++++++++++++++++++++++++>>>[-<<<++++++++++++++>>>>-]>[->]<+<[-<<<
+-->>>>-]>[->]<+<[-<<<++++++++++++++>>>>-]>[->]<+<[-<<<+>>>>-]>[->]<+<[-<<<------
+-------->>>>-]>[->]<+<[-<<<++++++++++++++++>>>>-]>[->]<+<[-<<<------------------
+>>>>-]>[->]<+<[-<<<->>>>-]>[->]<+<[-<<<----->>>>-]>[->]<+<[-<<<-->>>>-]>[->]<+<[
+-<<<+++>>>>-]>[->]<+<[-<<<++++++++++++++++++++++++++>>>>-]>[->]<+<<<<.>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<-
+>>>>>>>>>>>>>>>>-]>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>->]<+<<<<<<<<<<<<<<<-]>[>>>
+>>>>>>>>>>>[-]<<<<<<<<<<<<<<<+>->]<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<]<<<.>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<->>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>->]<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<-]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<+>->]<+<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]>
+[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>->]<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>[>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>->]<+<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
@@ -0,0 +1,1297 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 30000
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    tape[ptr] = 17;
+    tape[ptr + 1] = 1;
+    tape[ptr + 3] = 10;
+    tape[ptr + 4] = 1;
+    tape[ptr + 7] = 1;
+    tape[ptr + 10] = 1;
+    tape[ptr + 13] = 1;
+    tape[ptr + 16] = 1;
+    tape[ptr + 19] = 1;
+    tape[ptr + 22] = 1;
+    tape[ptr + 25] = 1;
+    tape[ptr + 28] = 1;
+    tape[ptr + 31] = 1;
+    tape[ptr + 34] = 1;
+    tape[ptr + 37] = 1;
+    tape[ptr + 40] = 1;
+    tape[ptr + 42] = 16;
+    tape[ptr + 43] = 1;
+    tape[ptr + 46] = 1;
+    tape[ptr + 49] = 1;
+    tape[ptr + 52] = 1;
+    tape[ptr + 55] = 1;
+    tape[ptr + 58] = 1;
+    tape[ptr + 61] = 1;
+    tape[ptr + 64] = 1;
+    tape[ptr + 67] = 1;
+    tape[ptr + 70] = 1;
+    tape[ptr + 73] = 1;
+    tape[ptr + 76] = 1;
+    tape[ptr + 79] = 1;
+    tape[ptr + 82] = 1;
+    tape[ptr + 84] = 1;
+    tape[ptr + 85] = 1;
+    tape[ptr + 88] = 1;
+    while (tape[ptr] != 0) {
+        tape[ptr + 6] = 40;
+        tape[ptr + 66] = 32;
+        tape[ptr + 81] = 1;
+        tape[ptr] -= 1;
+        ptr += 6;
+        while (tape[ptr] != 0) {
+            tape[ptr + 6] = 0;
+            tape[ptr + 9] = 12;
+            tape[ptr + 21] = 0;
+            tape[ptr + 24] = 0;
+            tape[ptr + 27] = 0;
+            tape[ptr + 30] = 0;
+            tape[ptr + 66] = 0;
+            tape[ptr + 69] = 0;
+            tape[ptr + 81] = 1;
+            tape[ptr] -= 1;
+            ptr += 81;
+            while (tape[ptr] != 0) {
+                tape[ptr - 24] = 8;
+                tape[ptr - 18] = 0;
+                ptr -= 54;
+                while (tape[ptr] != 0) {
+                    tape[ptr] -= 1;
+                    tape[ptr + 2] += 1;
+                    ptr += 30;
+                    while (tape[ptr] != 0) {
+                        tape[ptr] -= 1;
+                        tape[ptr + 1] -= 1;
+                        ptr += 1;
+                    }
+                    ptr += 1;
+                    while (tape[ptr] != 0) {
+                        tape[ptr + 5] = 1;
+                        tape[ptr] -= 1;
+                        ptr += 1;
+                    }
+                    tape[ptr - 1] += 1;
+                    ptr -= 32;
+                }
+                while (tape[ptr + 2] != 0) {
+                    tape[ptr] += 1;
+                    tape[ptr + 2] -= 1;
+                }
+                ptr += 36;
+                while (tape[ptr] != 0) {
+                    tape[ptr + 18] = 0;
+                    tape[ptr + 1] -= 1;
+                    ptr += 1;
+                }
+                ptr += 1;
+                if (tape[ptr] != 0) {
+                    tape[ptr - 7] = 8;
+                    tape[ptr - 1] = 0;
+                    ptr -= 34;
+                    while (tape[ptr] != 0) {
+                        tape[ptr] -= 1;
+                        tape[ptr + 2] += 1;
+                        ptr += 27;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 1] -= 1;
+                            ptr += 1;
+                        }
+                        ptr += 1;
+                        while (tape[ptr] != 0) {
+                            tape[ptr + 5] = 1;
+                            tape[ptr] -= 1;
+                            ptr += 1;
+                        }
+                        tape[ptr - 1] += 1;
+                        ptr -= 29;
+                    }
+                    while (tape[ptr + 2] != 0) {
+                        tape[ptr] += 1;
+                        tape[ptr + 2] -= 1;
+                    }
+                    ptr += 33;
+                    while (tape[ptr] != 0) {
+                        tape[ptr + 18] = 0;
+                        tape[ptr + 1] -= 1;
+                        ptr += 1;
+                    }
+                    ptr += 1;
+                    if (tape[ptr] != 0) {
+                        tape[ptr - 31] = 0;
+                        tape[ptr - 13] = 0;
+                        tape[ptr - 10] = 0;
+                        while (tape[ptr - 37] != 0) {
+                            tape[ptr - 37] -= 1;
+                            tape[ptr - 35] += 1;
+                            tape[ptr - 31] += 1;
+                        }
+                        while (tape[ptr - 35] != 0) {
+                            tape[ptr - 37] += 1;
+                            tape[ptr - 35] -= 1;
+                        }
+                        tape[ptr - 49] = 0;
+                        while (tape[ptr - 40] != 0) {
+                            tape[ptr - 49] += 1;
+                            tape[ptr - 40] -= 1;
+                            tape[ptr - 38] += 1;
+                        }
+                        while (tape[ptr - 38] != 0) {
+                            tape[ptr - 40] += 1;
+                            tape[ptr - 38] -= 1;
+                        }
+                        while (tape[ptr - 37] != 0) {
+                            tape[ptr - 37] -= 1;
+                            tape[ptr - 35] += 1;
+                            while (tape[ptr - 31] != 0) {
+                                tape[ptr - 31] -= 1;
+                                tape[ptr - 29] += 1;
+                                tape[ptr - 13] += 1;
+                            }
+                            while (tape[ptr - 29] != 0) {
+                                tape[ptr - 31] += 1;
+                                tape[ptr - 29] -= 1;
+                            }
+                        }
+                        while (tape[ptr - 35] != 0) {
+                            tape[ptr - 37] += 1;
+                            tape[ptr - 35] -= 1;
+                        }
+                        tape[ptr - 52] = 4;
+                        tape[ptr - 46] = 4;
+                        ptr -= 37;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr -= 12;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                tape[ptr + 3] -= 1;
+                                ptr += 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 32] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr -= 5;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr += 12;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        ptr -= 3;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr += 9;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 15] -= 1;
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                ptr -= 15;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 32] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 13;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr -= 9;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr -= 9;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 3] -= 1;
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr + 5] -= 1;
+                                    ptr += 5;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr + 1] -= 1;
+                                        ptr += 1;
+                                    }
+                                    ptr += 1;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 1] += 4;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 32] += 1;
+                                        ptr += 1;
+                                    }
+                                    tape[ptr - 7] -= 1;
+                                    tape[ptr - 1] += 1;
+                                    ptr -= 6;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 1;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr += 9;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        tape[ptr + 9] = 0;
+                        while (tape[ptr + 6] != 0) {
+                            tape[ptr + 6] -= 1;
+                            tape[ptr + 8] += 1;
+                            tape[ptr + 9] += 1;
+                        }
+                        while (tape[ptr + 8] != 0) {
+                            tape[ptr + 6] += 1;
+                            tape[ptr + 8] -= 1;
+                        }
+                        tape[ptr - 9] = 0;
+                        while (tape[ptr - 3] != 0) {
+                            tape[ptr - 9] += 1;
+                            tape[ptr - 3] -= 1;
+                            tape[ptr - 1] += 1;
+                        }
+                        while (tape[ptr - 1] != 0) {
+                            tape[ptr - 3] += 1;
+                            tape[ptr - 1] -= 1;
+                        }
+                        while (tape[ptr + 6] != 0) {
+                            tape[ptr + 6] -= 1;
+                            tape[ptr + 8] += 1;
+                            while (tape[ptr + 9] != 0) {
+                                tape[ptr + 9] -= 1;
+                                tape[ptr + 11] += 1;
+                                tape[ptr + 30] += 1;
+                            }
+                            while (tape[ptr + 11] != 0) {
+                                tape[ptr + 9] += 1;
+                                tape[ptr + 11] -= 1;
+                            }
+                        }
+                        while (tape[ptr + 8] != 0) {
+                            tape[ptr + 6] += 1;
+                            tape[ptr + 8] -= 1;
+                        }
+                        tape[ptr - 12] = 4;
+                        tape[ptr - 6] = 4;
+                        ptr += 6;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr -= 15;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                tape[ptr + 3] -= 1;
+                                ptr += 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 35] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr -= 5;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr += 15;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        ptr -= 9;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr += 12;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 15] -= 1;
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                ptr -= 15;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 35] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 13;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr -= 12;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr -= 6;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 3] -= 1;
+                                tape[ptr] -= 1;
+                                tape[ptr + 2] += 1;
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr + 5] -= 1;
+                                    ptr += 5;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr + 1] -= 1;
+                                        ptr += 1;
+                                    }
+                                    ptr += 1;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 1] += 4;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 35] += 1;
+                                        ptr += 1;
+                                    }
+                                    tape[ptr - 7] -= 1;
+                                    tape[ptr - 1] += 1;
+                                    ptr -= 6;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 1;
+                            }
+                            while (tape[ptr + 2] != 0) {
+                                tape[ptr] += 1;
+                                tape[ptr + 2] -= 1;
+                            }
+                            ptr += 6;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        tape[ptr + 36] = 64;
+                        tape[ptr + 42] = 0;
+                        ptr += 30;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr += 6;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                tape[ptr + 1] -= 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 5] = 1;
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            ptr -= 8;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        ptr += 3;
+                        while (tape[ptr] != 0) {
+                            tape[ptr] -= 1;
+                            tape[ptr + 2] += 1;
+                            ptr += 3;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                tape[ptr + 1] -= 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 5] = 1;
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            ptr -= 5;
+                        }
+                        while (tape[ptr + 2] != 0) {
+                            tape[ptr] += 1;
+                            tape[ptr + 2] -= 1;
+                        }
+                        ptr += 9;
+                        while (tape[ptr] != 0) {
+                            tape[ptr + 18] = 0;
+                            tape[ptr + 1] -= 1;
+                            ptr += 1;
+                        }
+                        ptr += 1;
+                        while (tape[ptr] != 0) {
+                            tape[ptr + 8] = 0;
+                            ptr += 5;
+                            while (tape[ptr] != 0) {
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 5] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                tape[ptr + 2] -= 1;
+                                ptr += 2;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                ptr -= 4;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    tape[ptr + 6] += 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                tape[ptr + 2] -= 1;
+                                ptr += 3;
+                            }
+                            tape[ptr - 32] = 0;
+                            tape[ptr - 29] = 0;
+                            tape[ptr - 1] += 1;
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 39] = 0;
+                                while (tape[ptr - 42] != 0) {
+                                    tape[ptr - 42] -= 1;
+                                    tape[ptr - 40] += 1;
+                                    tape[ptr - 39] += 1;
+                                }
+                                while (tape[ptr - 40] != 0) {
+                                    tape[ptr - 42] += 1;
+                                    tape[ptr - 40] -= 1;
+                                }
+                                tape[ptr - 57] = 0;
+                                while (tape[ptr - 51] != 0) {
+                                    tape[ptr - 57] += 1;
+                                    tape[ptr - 51] -= 1;
+                                    tape[ptr - 49] += 1;
+                                }
+                                while (tape[ptr - 49] != 0) {
+                                    tape[ptr - 51] += 1;
+                                    tape[ptr - 49] -= 1;
+                                }
+                                while (tape[ptr - 45] != 0) {
+                                    tape[ptr - 45] -= 1;
+                                    tape[ptr - 43] += 1;
+                                    while (tape[ptr - 39] != 0) {
+                                        tape[ptr - 39] -= 1;
+                                        tape[ptr - 37] += 1;
+                                        tape[ptr - 33] += 2;
+                                    }
+                                    while (tape[ptr - 37] != 0) {
+                                        tape[ptr - 39] += 1;
+                                        tape[ptr - 37] -= 1;
+                                    }
+                                }
+                                while (tape[ptr - 43] != 0) {
+                                    tape[ptr - 45] += 1;
+                                    tape[ptr - 43] -= 1;
+                                }
+                                tape[ptr - 60] = 4;
+                                tape[ptr - 54] = 4;
+                                ptr -= 45;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr -= 12;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        tape[ptr + 3] -= 1;
+                                        ptr += 3;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr] -= 1;
+                                            tape[ptr + 20] += 2;
+                                            ptr += 1;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr -= 5;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr += 12;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr += 9;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 15] -= 1;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        ptr -= 15;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr] -= 1;
+                                            tape[ptr + 20] += 2;
+                                            ptr += 1;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr += 13;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr -= 9;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr -= 9;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 3] -= 1;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        ptr -= 3;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr + 5] -= 1;
+                                            ptr += 5;
+                                            while (tape[ptr] != 0) {
+                                                tape[ptr + 1] -= 1;
+                                                ptr += 1;
+                                            }
+                                            ptr += 1;
+                                            while (tape[ptr] != 0) {
+                                                tape[ptr - 1] += 4;
+                                                tape[ptr] -= 1;
+                                                tape[ptr + 20] += 2;
+                                                ptr += 1;
+                                            }
+                                            tape[ptr - 7] -= 1;
+                                            tape[ptr - 1] += 1;
+                                            ptr -= 6;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr += 1;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr += 9;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                tape[ptr + 49] -= 1;
+                                ptr += 49;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 40] = 0;
+                                while (tape[ptr - 43] != 0) {
+                                    tape[ptr - 43] -= 1;
+                                    tape[ptr - 41] += 1;
+                                    tape[ptr - 40] += 1;
+                                }
+                                while (tape[ptr - 41] != 0) {
+                                    tape[ptr - 43] += 1;
+                                    tape[ptr - 41] -= 1;
+                                }
+                                tape[ptr - 58] = 0;
+                                while (tape[ptr - 52] != 0) {
+                                    tape[ptr - 58] += 1;
+                                    tape[ptr - 52] -= 1;
+                                    tape[ptr - 50] += 1;
+                                }
+                                while (tape[ptr - 50] != 0) {
+                                    tape[ptr - 52] += 1;
+                                    tape[ptr - 50] -= 1;
+                                }
+                                while (tape[ptr - 46] != 0) {
+                                    tape[ptr - 46] -= 1;
+                                    tape[ptr - 44] += 1;
+                                    while (tape[ptr - 40] != 0) {
+                                        tape[ptr - 40] -= 1;
+                                        tape[ptr - 38] += 1;
+                                        tape[ptr - 31] += 2;
+                                    }
+                                    while (tape[ptr - 38] != 0) {
+                                        tape[ptr - 40] += 1;
+                                        tape[ptr - 38] -= 1;
+                                    }
+                                }
+                                while (tape[ptr - 44] != 0) {
+                                    tape[ptr - 46] += 1;
+                                    tape[ptr - 44] -= 1;
+                                }
+                                tape[ptr - 61] = 4;
+                                tape[ptr - 55] = 4;
+                                ptr -= 46;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr -= 12;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        tape[ptr + 3] -= 1;
+                                        ptr += 3;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr] -= 1;
+                                            tape[ptr + 23] += 2;
+                                            ptr += 1;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr -= 5;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr += 12;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr += 9;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 15] -= 1;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        ptr -= 15;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr] -= 1;
+                                            tape[ptr + 23] += 2;
+                                            ptr += 1;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr += 13;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr -= 9;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 2] += 1;
+                                    ptr -= 9;
+                                    while (tape[ptr] != 0) {
+                                        tape[ptr - 3] -= 1;
+                                        tape[ptr] -= 1;
+                                        tape[ptr + 2] += 1;
+                                        ptr -= 3;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr + 1] -= 1;
+                                            ptr += 1;
+                                        }
+                                        ptr += 1;
+                                        while (tape[ptr] != 0) {
+                                            tape[ptr - 1] += 4;
+                                            tape[ptr + 5] -= 1;
+                                            ptr += 5;
+                                            while (tape[ptr] != 0) {
+                                                tape[ptr + 1] -= 1;
+                                                ptr += 1;
+                                            }
+                                            ptr += 1;
+                                            while (tape[ptr] != 0) {
+                                                tape[ptr - 1] += 4;
+                                                tape[ptr] -= 1;
+                                                tape[ptr + 23] += 2;
+                                                ptr += 1;
+                                            }
+                                            tape[ptr - 7] -= 1;
+                                            tape[ptr - 1] += 1;
+                                            ptr -= 6;
+                                        }
+                                        tape[ptr - 1] += 1;
+                                        ptr += 1;
+                                    }
+                                    while (tape[ptr + 2] != 0) {
+                                        tape[ptr] += 1;
+                                        tape[ptr + 2] -= 1;
+                                    }
+                                    ptr += 9;
+                                }
+                                while (tape[ptr + 2] != 0) {
+                                    tape[ptr] += 1;
+                                    tape[ptr + 2] -= 1;
+                                }
+                                tape[ptr + 49] -= 1;
+                                ptr += 50;
+                            }
+                            tape[ptr - 1] += 1;
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                while (tape[ptr - 15] != 0) {
+                                    tape[ptr - 21] += 1;
+                                    tape[ptr - 15] -= 1;
+                                    tape[ptr - 13] += 1;
+                                }
+                                while (tape[ptr - 13] != 0) {
+                                    tape[ptr - 15] += 1;
+                                    tape[ptr - 13] -= 1;
+                                }
+                                tape[ptr + 1] -= 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                while (tape[ptr - 16] != 0) {
+                                    tape[ptr - 25] += 1;
+                                    tape[ptr - 16] -= 1;
+                                    tape[ptr - 14] += 1;
+                                }
+                                while (tape[ptr - 14] != 0) {
+                                    tape[ptr - 16] += 1;
+                                    tape[ptr - 14] -= 1;
+                                }
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                while (tape[ptr - 42] != 0) {
+                                    tape[ptr - 42] -= 1;
+                                    tape[ptr - 40] += 1;
+                                    tape[ptr - 39] += 1;
+                                }
+                                while (tape[ptr - 40] != 0) {
+                                    tape[ptr - 42] += 1;
+                                    tape[ptr - 40] -= 1;
+                                }
+                                tape[ptr + 1] -= 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                while (tape[ptr - 43] != 0) {
+                                    tape[ptr - 43] -= 1;
+                                    tape[ptr - 41] += 1;
+                                    tape[ptr - 37] += 1;
+                                }
+                                while (tape[ptr - 41] != 0) {
+                                    tape[ptr - 43] += 1;
+                                    tape[ptr - 41] -= 1;
+                                }
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 11] = 0;
+                            tape[ptr - 1] += 1;
+                            ptr -= 26;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                ptr -= 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 4] += 1;
+                                    tape[ptr] -= 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 1;
+                            }
+                            ptr -= 6;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 1] -= 1;
+                                tape[ptr + 21] += 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            while (tape[ptr - 2] != 0) {
+                                tape[ptr - 2] -= 1;
+                                tape[ptr + 1] += 1;
+                            }
+                            tape[ptr - 23] = 0;
+                            tape[ptr - 5] = 4;
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr - 6] -= 1;
+                                tape[ptr] -= 1;
+                                ptr -= 6;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 19] += 1;
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr += 4;
+                            }
+                            tape[ptr - 27] = 4;
+                            while (tape[ptr - 6] != 0) {
+                                tape[ptr - 27] -= 1;
+                                tape[ptr - 6] -= 1;
+                            }
+                            tape[ptr + 15] = 0;
+                            ptr -= 12;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                ptr += 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr] -= 1;
+                                    tape[ptr + 5] += 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr -= 5;
+                            }
+                            ptr += 9;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 1] -= 1;
+                                tape[ptr + 18] += 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            while (tape[ptr - 2] != 0) {
+                                tape[ptr - 8] += 1;
+                                tape[ptr - 2] -= 1;
+                            }
+                            tape[ptr - 20] = 0;
+                            tape[ptr - 5] = 4;
+                            ptr -= 8;
+                            while (tape[ptr] != 0) {
+                                tape[ptr] -= 1;
+                                tape[ptr + 3] -= 1;
+                                ptr += 3;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr + 1] -= 1;
+                                    ptr += 1;
+                                }
+                                ptr += 1;
+                                while (tape[ptr] != 0) {
+                                    tape[ptr - 16] += 1;
+                                    tape[ptr - 1] += 4;
+                                    tape[ptr] -= 1;
+                                    ptr += 1;
+                                }
+                                tape[ptr - 1] += 1;
+                                ptr -= 5;
+                            }
+                            tape[ptr - 21] = 4;
+                            while (tape[ptr + 3] != 0) {
+                                tape[ptr - 21] -= 1;
+                                tape[ptr + 3] -= 1;
+                            }
+                            tape[ptr - 36] += 1;
+                            tape[ptr - 33] -= 1;
+                            ptr -= 33;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 1] -= 1;
+                                ptr += 1;
+                            }
+                            ptr += 1;
+                            while (tape[ptr] != 0) {
+                                tape[ptr + 71] = 0;
+                                tape[ptr] -= 1;
+                                ptr += 1;
+                            }
+                            tape[ptr - 1] += 1;
+                            tape[ptr + 53] -= 1;
+                            ptr += 54;
+                        }
+                    }
+                }
+                tape[ptr - 1] += 1;
+                ptr += 16;
+            }
+            tape[ptr - 78] = 32;
+            ptr -= 75;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 14;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 2;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 14;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 1;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 14;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 16;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 18;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 1;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 5;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] -= 2;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 3;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 2;
+            while (tape[ptr] != 0) {
+                tape[ptr - 3] += 26;
+                tape[ptr] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 5;
+            printf("%c", tape[ptr]);
+            ptr += 57;
+            while (tape[ptr] != 0) {
+                ptr += 15;
+                while (tape[ptr] != 0) {
+                    tape[ptr - 15] -= 1;
+                    tape[ptr + 1] -= 1;
+                    ptr += 1;
+                }
+                ptr += 1;
+                while (tape[ptr] != 0) {
+                    tape[ptr - 16] += 1;
+                    tape[ptr] -= 1;
+                    ptr += 1;
+                }
+                tape[ptr - 16] -= 1;
+                tape[ptr - 1] += 1;
+                ptr -= 16;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr + 14] = 0;
+                tape[ptr - 1] += 1;
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 1] += 1;
+            ptr -= 62;
+        }
+        ptr -= 3;
+        printf("%c", tape[ptr]);
+        ptr += 39;
+        while (tape[ptr] != 0) {
+            ptr += 42;
+            while (tape[ptr] != 0) {
+                tape[ptr - 42] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr - 43] += 1;
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 43] -= 1;
+            tape[ptr - 1] += 1;
+            ptr -= 43;
+        }
+        ptr += 1;
+        while (tape[ptr] != 0) {
+            tape[ptr + 41] = 0;
+            tape[ptr - 1] += 1;
+            tape[ptr] -= 1;
+            ptr += 1;
+        }
+        tape[ptr - 1] += 1;
+        ptr -= 2;
+        while (tape[ptr] != 0) {
+            ptr += 42;
+            while (tape[ptr] != 0) {
+                tape[ptr - 42] -= 1;
+                tape[ptr + 1] -= 1;
+                ptr += 1;
+            }
+            ptr += 1;
+            while (tape[ptr] != 0) {
+                tape[ptr - 43] += 1;
+                tape[ptr] -= 1;
+                ptr += 1;
+            }
+            tape[ptr - 43] -= 1;
+            tape[ptr - 1] += 1;
+            ptr -= 43;
+        }
+        ptr += 1;
+        while (tape[ptr] != 0) {
+            tape[ptr + 41] = 0;
+            tape[ptr - 1] += 1;
+            tape[ptr] -= 1;
+            ptr += 1;
+        }
+        tape[ptr - 1] += 1;
+        ptr -= 44;
+    }
+
+    return 0;
+}
@@ -0,0 +1,5 @@
+Multiplies into several cells on either side of the counter and outputs each
+product
+
+>>+++++++[-<<+++++>++++++>>+++>>++<<<]<<.>.>>.>>.
+[-]<<[-]<<<[-]+++++[>>>++++++++++<<<-]>>>+++.[<+<+>>-]<.<.
@@ -0,0 +1,18 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 3
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("#*\025\01655_", 1, 7, stdout);
+    tape[ptr + 1] = 95;
+    tape[ptr + 2] = 53;
+    ptr += 1;
+
+    return 0;
+}
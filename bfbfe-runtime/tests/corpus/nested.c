@@ -0,0 +1,29 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 4
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    tape[ptr] = -1;
+    while (tape[ptr] != 0) {
+        tape[ptr + 1] -= 2;
+        while (tape[ptr + 1] != 0) {
+            tape[ptr + 2] -= 2;
+            while (tape[ptr + 2] != 0) {
+                tape[ptr + 2] -= 2;
+                tape[ptr + 3] += 1;
+            }
+            tape[ptr + 1] -= 2;
+        }
+        tape[ptr] -= 1;
+    }
+    ptr += 3;
+    printf("%c", tape[ptr]);
+
+    return 0;
+}
@@ -0,0 +1,3 @@
+Stores every byte of input in a cell of its own and outputs them backwards
+
+>,+[->,+]<[.<]
@@ -0,0 +1,28 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 30000
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    ptr += 1;
+    tape[ptr] = getchar();
+    tape[ptr] += 1;
+    while (tape[ptr] != 0) {
+        tape[ptr] -= 1;
+        ptr += 1;
+        tape[ptr] = getchar();
+        tape[ptr] += 1;
+    }
+    ptr -= 1;
+    while (tape[ptr] != 0) {
+        printf("%c", tape[ptr]);
+        ptr -= 1;
+    }
+
+    return 0;
+}
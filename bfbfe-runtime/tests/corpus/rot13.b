@@ -0,0 +1,7 @@
+Applies ROT13 to every letter of input
+
+-,+[
+    -[>>++++[>++++++++<-]<+<-[>+>+>-[>>>]<[[>+<-]>>+>]<<<<<-]]>>>[-]+>--[-[<->+++[-]]]<[
+        ++++++++++++<[>-[>+>>]>[+[<+>-]>+>>]<<<<<-]>>[<+>-]>[-[-<<[-]>>]<<[<<->>-]>>]<<[<<+>>-]
+    ]<[-]<.[-]<-,+
+]
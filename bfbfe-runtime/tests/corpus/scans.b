@@ -0,0 +1,8 @@
+Scans over runs of cells with strides of one two and three
+
+>+>+>+>+>>+>+>+>+>+
+[<]<[<]>>
++[>>]<<<.
+[-]>>>>>>>>>>+>>+>>+>>+>>>>
++[<<]>>.
+>+>>+>>+>>+>>+>>>>+[<<<]>.
@@ -0,0 +1,35 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 43
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("\001\001\000", 1, 3, stdout);
+    tape[ptr + 1] = 1;
+    tape[ptr + 2] = 2;
+    tape[ptr + 3] = 1;
+    tape[ptr + 4] = 1;
+    tape[ptr + 6] = 1;
+    tape[ptr + 7] = 1;
+    tape[ptr + 8] = 1;
+    tape[ptr + 10] = 1;
+    tape[ptr + 19] = 1;
+    tape[ptr + 21] = 1;
+    tape[ptr + 23] = 1;
+    tape[ptr + 25] = 1;
+    tape[ptr + 29] = 1;
+    tape[ptr + 30] = 1;
+    tape[ptr + 32] = 1;
+    tape[ptr + 34] = 1;
+    tape[ptr + 36] = 1;
+    tape[ptr + 38] = 1;
+    tape[ptr + 42] = 1;
+    ptr += 40;
+
+    return 0;
+}
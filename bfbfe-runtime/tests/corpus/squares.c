@@ -0,0 +1,26 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 23
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("0\0121\0124\0129\01216\01225\01236\01249\01264\01281\012100\012121\012144\012169\012196\012225\012256\012289\012324\012361\012400\012441\012484\012529\012576\012625\012676\012729\012784\012841\012900\012961\0121024\0121089\0121156\0121225\0121296\0121369\0121444\0121521\0121600\0121681\0121764\0121849\0121936\0122025\0122116\0122209\0122304\0122401\0122500\0122601\0122704\0122809\0122916\0123025\0123136\0123249\0123364\0123481\0123600\0123721\0123844\0123969\0124096\0124225\0124356\0124489\0124624\0124761\0124900\0125041\0125184\0125329\0125476\0125625\0125776\0125929\0126084\0126241\0126400\0126561\0126724\0126889\0127056\0127225\0127396\0127569\0127744\0127921\0128100\0128281\0128464\0128649\0128836\0129025\0129216\0129409\0129604\0129801\01210000\012", 1, 460, stdout);
+    tape[ptr + 1] = 203;
+    tape[ptr + 6] = 2;
+    tape[ptr + 7] = 1;
+    tape[ptr + 9] = 1;
+    tape[ptr + 10] = 1;
+    tape[ptr + 13] = 9;
+    tape[ptr + 15] = 2;
+    tape[ptr + 16] = 1;
+    tape[ptr + 19] = 1;
+    tape[ptr + 21] = 1;
+    tape[ptr + 22] = 1;
+
+    return 0;
+}
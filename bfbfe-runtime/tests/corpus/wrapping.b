@@ -0,0 +1,6 @@
+Cells wrap around in both directions
+
+-.+.
+[-]--[>+<--]>.
+[-]+[+]+.
+>>-[-<+>]<.
@@ -0,0 +1,31 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+#define TAPE_SIZE 4
+
+int main(void)
+{
+    uint8_t tape[TAPE_SIZE] = { 0 };
+    size_t ptr = 0;
+
+    fwrite("\377\000", 1, 2, stdout);
+    tape[ptr] = -2;
+    while (tape[ptr] != 0) {
+        tape[ptr] -= 2;
+        tape[ptr + 1] += 1;
+    }
+    ptr += 1;
+    printf("%c", tape[ptr]);
+    tape[ptr] = 1;
+    fwrite("\001", 1, 1, stdout);
+    tape[ptr + 2] = -1;
+    while (tape[ptr + 2] != 0) {
+        tape[ptr + 1] += 1;
+        tape[ptr + 2] -= 1;
+    }
+    ptr += 1;
+    printf("%c", tape[ptr]);
+
+    return 0;
+}
@@ -0,0 +1,362 @@
+#![allow(clippy::std_instead_of_core)]
+
+//! Runs every program of the corpus in `tests/corpus` every way it can, and
+//! checks that each of them produces the recorded output: the unoptimized
+//! IR in the interpreter, the optimized IR in the interpreter and in the JIT
+//! when the `jit` feature is enabled, the optimized IR transpiled back to
+//! Brainfuck and interpreted again, and the optimized IR through every
+//! backend whose tools are available, such as the C backend compiled with
+//! `cc` or the Go backend built with `go`.
+//!
+//! A program `name.b` reads its input from `name.in`, if there is one, and
+//! is expected to output exactly `name.out`.
+
+mod common;
+
+use std::ffi::OsString;
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_whole_program;
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+#[cfg(feature = "jit")]
+use bfbfe_runtime::jit::JitProgram;
+use bfbfe_runtime::limits::ExecutionLimits;
+use bfbfe_transpile::backend::bf_backend::compile_to_bf;
+use bfbfe_transpile::backend::c_backend::compile_to_c;
+use bfbfe_transpile::backend::go_backend::compile_to_go;
+use bfbfe_transpile::backend::jvm_backend::compile_to_jvm;
+use bfbfe_transpile::backend::qbe_backend::compile_to_qbe;
+use bfbfe_transpile::backend::zig_backend::compile_to_zig;
+use common::corpus;
+use common::Case;
+
+/// Bounds every run, so that a miscompiled loop fails its test instead of
+/// hanging it.
+const MAX_STEPS: u64 = 100_000_000;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds and runs a backend's output for a case, or returns `None` if the
+/// tools to build it with aren't available.
+type Runner = fn(&Case, &Path) -> Option<Result<Vec<u8>, String>>;
+
+/// Reads the file recorded for `case` with the given extension.
+fn read(case: &Case, extension: &str) -> Option<Vec<u8>>
+{
+    fs::read(case.path.with_extension(extension)).ok()
+}
+
+fn interpret(bytecode: &Bytecode, input: &[u8]) -> Result<Vec<u8>, String>
+{
+    let limits = ExecutionLimits {
+        max_steps: Some(MAX_STEPS),
+        timeout: Some(TIMEOUT),
+        ..ExecutionLimits::default()
+    };
+
+    let mut output = Vec::new();
+    Interpreter::<u8>::new()
+        .run_with_limits(bytecode, &mut &*input, &mut output, &limits)
+        .map_err(|e| e.to_string())?;
+    Ok(output)
+}
+
+/// Runs a step of building a program, or returns `None` if the tool for it
+/// isn't available.
+fn build(command: &mut Command) -> Option<Result<(), String>>
+{
+    let status = command.status().ok()?;
+    if !status.success() {
+        return Some(Err(format!(
+            "{} failed with {status}",
+            command.get_program().to_string_lossy()
+        )));
+    }
+    Some(Ok(()))
+}
+
+/// Runs a program built for `case` on its input, and returns what it output.
+fn run(case: &Case, command: &mut Command, dir: &Path) -> Result<Vec<u8>, String>
+{
+    let output = dir.join(format!("{}.out", case.name));
+    let stdin = File::open(case.path.with_extension("in")).map_or_else(|_| Stdio::null(), Stdio::from);
+    let stdout = File::create(&output).map_err(|e| e.to_string())?;
+    let mut child = command.stdin(stdin).stdout(stdout).spawn().map_err(|e| e.to_string())?;
+
+    let deadline = Instant::now() + TIMEOUT;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            return Err(format!("timed out after {TIMEOUT:?}"));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+
+    if !status.success() {
+        return Err(format!("exited with {status}"));
+    }
+    fs::read(output).map_err(|e| e.to_string())
+}
+
+/// Compiles the C backend's output for `case` and runs it, or returns `None`
+/// if there is no C compiler to build it with.
+fn run_c(case: &Case, dir: &Path) -> Option<Result<Vec<u8>, String>>
+{
+    let source = dir.join(format!("{}.c", case.name));
+    let executable = dir.join(&case.name);
+    fs::write(&source, compile_to_c(&case.block.clone().optimize())).expect("failed to write C source");
+
+    if let Err(e) = build(Command::new("cc").arg("-O1").arg("-o").arg(&executable).arg(&source))? {
+        return Some(Err(e));
+    }
+    Some(run(case, &mut Command::new(&executable), dir))
+}
+
+/// Compiles the QBE backend's output for `case` with `qbe`, assembles it
+/// with `cc` and runs it, or returns `None` if either isn't available.
+fn run_qbe(case: &Case, dir: &Path) -> Option<Result<Vec<u8>, String>>
+{
+    let source = dir.join(format!("{}.ssa", case.name));
+    let assembly = dir.join(format!("{}.s", case.name));
+    let executable = dir.join(&case.name);
+    fs::write(&source, compile_to_qbe(&case.block.clone().optimize())).expect("failed to write QBE source");
+
+    if let Err(e) = build(Command::new("qbe").arg("-o").arg(&assembly).arg(&source))? {
+        return Some(Err(e));
+    }
+    if let Err(e) = build(Command::new("cc").arg("-o").arg(&executable).arg(&assembly))? {
+        return Some(Err(e));
+    }
+    Some(run(case, &mut Command::new(&executable), dir))
+}
+
+/// Builds the Go backend's output for `case` with `go` and runs it, or
+/// returns `None` if Go isn't available.
+fn run_go(case: &Case, dir: &Path) -> Option<Result<Vec<u8>, String>>
+{
+    let source = dir.join(format!("{}.go", case.name));
+    let executable = dir.join(&case.name);
+    let code = match compile_to_go(&case.block.clone().optimize()) {
+        Ok(code) => code,
+        Err(e) => return Some(Err(e.to_string())),
+    };
+    fs::write(&source, code).expect("failed to write Go source");
+
+    if let Err(e) = build(Command::new("go").arg("build").arg("-o").arg(&executable).arg(&source))? {
+        return Some(Err(e));
+    }
+    Some(run(case, &mut Command::new(&executable), dir))
+}
+
+/// Builds the Zig backend's output for `case` with `zig` and runs it, or
+/// returns `None` if Zig isn't available.
+fn run_zig(case: &Case, dir: &Path) -> Option<Result<Vec<u8>, String>>
+{
+    let source = dir.join(format!("{}.zig", case.name));
+    let executable = dir.join(&case.name);
+    let code = match compile_to_zig(&case.block.clone().optimize()) {
+        Ok(code) => code,
+        Err(e) => return Some(Err(e.to_string())),
+    };
+    fs::write(&source, code).expect("failed to write Zig source");
+
+    let mut emit = OsString::from("-femit-bin=");
+    emit.push(&executable);
+    if let Err(e) = build(
+        Command::new("zig")
+            .current_dir(dir)
+            .arg("build-exe")
+            .arg(&source)
+            .arg(emit),
+    )? {
+        return Some(Err(e));
+    }
+    Some(run(case, &mut Command::new(&executable), dir))
+}
+
+/// Writes the JVM backend's class file for `case` and runs it with `java`, or
+/// returns `None` if there is no JVM to run it with.
+fn run_jvm(case: &Case, dir: &Path) -> Option<Result<Vec<u8>, String>>
+{
+    let classes = dir.join(&case.name);
+    fs::create_dir_all(&classes).expect("failed to create class directory");
+    let class = match compile_to_jvm(&case.block.clone().optimize()) {
+        Ok(class) => class,
+        Err(e) => return Some(Err(e.to_string())),
+    };
+    fs::write(classes.join("Main.class"), class).expect("failed to write class file");
+
+    // Nothing is built with java, so check that there is one before running
+    if let Err(e) = build(Command::new("java").arg("-version").stderr(Stdio::null()))? {
+        return Some(Err(e));
+    }
+    Some(run(
+        case,
+        Command::new("java").arg("-cp").arg(&classes).arg("Main"),
+        dir,
+    ))
+}
+
+/// Checks a backend whose output is built with external tools, skipping it
+/// if `run` finds that they aren't available.
+fn check_external(backend: &str, tools: &str, run: Runner)
+{
+    let dir = std::env::temp_dir().join("bfbfe-differential").join(backend);
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+
+    let cases = corpus();
+    let mut results = Vec::new();
+    for case in &cases {
+        let Some(result) = run(case, &dir) else {
+            eprintln!("Skipping the {backend} backend, as {tools} is not available");
+            return;
+        };
+        results.push((case, result));
+    }
+
+    check(&format!("the {backend} backend"), results);
+}
+
+/// Fails with every case whose output differs from what was recorded.
+fn check(backend: &str, results: Vec<(&Case, Result<Vec<u8>, String>)>)
+{
+    let failures = results
+        .into_iter()
+        .filter_map(|(case, result)| match result {
+            Ok(output) => {
+                let expected = read(case, "out").unwrap_or_else(|| panic!("{}.out is missing", case.name));
+                (output != expected).then(|| {
+                    format!(
+                        "{}: expected {:?}, got {:?}",
+                        case.name,
+                        String::from_utf8_lossy(&expected),
+                        String::from_utf8_lossy(&output)
+                    )
+                })
+            }
+            Err(e) => Some(format!("{}: {e}", case.name)),
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "{backend} disagrees with the corpus:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn unoptimized_interpreter()
+{
+    let cases = corpus();
+    let results = cases
+        .iter()
+        .map(|case| {
+            let result = Bytecode::compile_unoptimized(&case.block)
+                .map_err(|e| e.to_string())
+                .and_then(|bytecode| interpret(&bytecode, &read(case, "in").unwrap_or_default()));
+            (case, result)
+        })
+        .collect();
+
+    check("the unoptimized interpreter", results);
+}
+
+#[test]
+fn optimized_interpreter()
+{
+    let cases = corpus();
+    let results = cases
+        .iter()
+        .map(|case| {
+            let result = Bytecode::compile(&case.block.clone().optimize())
+                .map_err(|e| e.to_string())
+                .and_then(|bytecode| interpret(&bytecode, &read(case, "in").unwrap_or_default()));
+            (case, result)
+        })
+        .collect();
+
+    check("the optimized interpreter", results);
+}
+
+#[cfg(feature = "jit")]
+#[test]
+fn jit()
+{
+    let cases = corpus();
+    let results = cases
+        .iter()
+        .map(|case| {
+            let result = JitProgram::compile(&case.block.clone().optimize())
+                .and_then(|program| {
+                    let mut output = Vec::new();
+                    program.run(&mut &*read(case, "in").unwrap_or_default(), &mut output)?;
+                    Ok(output)
+                })
+                .map_err(|e| e.to_string());
+            (case, result)
+        })
+        .collect();
+
+    check("the JIT", results);
+}
+
+#[test]
+fn brainfuck_backend()
+{
+    let cases = corpus();
+    let results = cases
+        .iter()
+        .map(|case| {
+            let result = compile_to_bf(&case.block.clone().optimize())
+                .map_err(|e| e.to_string())
+                .map(|source| tokenize_whole_program(&source.chars().collect::<Vec<_>>()))
+                .and_then(|tokens| instructionize(&tokens).map_err(|e| e.to_string()))
+                .and_then(|block| Bytecode::compile_unoptimized(&block).map_err(|e| e.to_string()))
+                .and_then(|bytecode| interpret(&bytecode, &read(case, "in").unwrap_or_default()));
+            (case, result)
+        })
+        .collect();
+
+    check("the Brainfuck backend", results);
+}
+
+#[test]
+fn c_backend()
+{
+    check_external("C", "cc", run_c);
+}
+
+#[test]
+fn qbe_backend()
+{
+    check_external("QBE", "qbe or cc", run_qbe);
+}
+
+#[test]
+fn go_backend()
+{
+    check_external("Go", "go", run_go);
+}
+
+#[test]
+fn zig_backend()
+{
+    check_external("Zig", "zig", run_zig);
+}
+
+#[test]
+fn jvm_backend()
+{
+    check_external("JVM", "java", run_jvm);
+}
@@ -0,0 +1,91 @@
+#![allow(clippy::std_instead_of_core)]
+
+//! Checks the code generated for every program of the corpus in
+//! `tests/corpus` against what was recorded for it, so that changes to the
+//! optimizer or the backends show up as a diff: the optimized IR, as listed
+//! by `--target ir`, in `name.ir`, and the C source in `name.c`.
+//!
+//! Running the tests with `BLESS=1` records the current output instead.
+
+mod common;
+
+use std::fs;
+
+use bfbfe_ir::block::IRBlock;
+use bfbfe_transpile::backend::c_backend::compile_to_c;
+use bfbfe_transpile::backend::ir_backend::compile_to_ir;
+use common::corpus;
+
+fn blessing() -> bool
+{
+    std::env::var_os("BLESS").is_some_and(|bless| bless != "0")
+}
+
+/// Describes where `actual` first departs from `expected`.
+fn first_difference(expected: &str, actual: &str) -> String
+{
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+
+    for line in 1_usize.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(a), Some(b)) if a == b => {}
+            (None, None) => break,
+            (a, b) => {
+                return format!(
+                    "line {line}: expected {:?}, got {:?}",
+                    a.unwrap_or("<end>"),
+                    b.unwrap_or("<end>")
+                );
+            }
+        }
+    }
+
+    String::from("only the line endings differ")
+}
+
+/// Compares the code `emit` generates for every program with the file
+/// recorded for it with `extension`, or records it when blessing.
+fn check(extension: &str, emit: fn(&IRBlock) -> String)
+{
+    let bless = blessing();
+    let mut failures = Vec::new();
+
+    for case in corpus() {
+        let actual = emit(&case.block.optimize());
+        let path = case.path.with_extension(extension);
+
+        if bless {
+            fs::write(&path, &actual).unwrap_or_else(|e| panic!("failed to write {}: {e}", path.display()));
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "{}.{extension}: {}",
+                case.name,
+                first_difference(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!("{}.{extension} is missing", case.name)),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "the generated code differs from the corpus, rerun with BLESS=1 if that is intended:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn ir()
+{
+    check("ir", compile_to_ir);
+}
+
+#[test]
+fn c()
+{
+    check("c", compile_to_c);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "jit")]
+
+//! Programs whose pointer can leave the tape, which the JIT has to check
+//! rather than trusting the footprint of the program.
+
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_whole_program;
+use bfbfe_runtime::jit::JitProgram;
+use bfbfe_runtime::runtime_error::RuntimeError;
+use bfbfe_runtime::TAPE_SIZE;
+
+fn run(source: &str) -> Result<Vec<u8>, RuntimeError>
+{
+    let tokens = tokenize_whole_program(&source.chars().collect::<Vec<_>>());
+    let program = JitProgram::compile(&instructionize(&tokens)?.optimize())?;
+
+    let mut output = Vec::new();
+    program.run(&mut &[][..], &mut output)?;
+    Ok(output)
+}
+
+#[test]
+fn open_footprint_stops_at_the_end_of_the_tape()
+{
+    assert!(matches!(run("+[>+]"), Err(RuntimeError::PointerOutOfBounds)));
+}
+
+#[test]
+fn moving_left_of_the_tape_stops()
+{
+    assert!(matches!(run("+[<+]"), Err(RuntimeError::PointerOutOfBounds)));
+    assert!(matches!(run("<+."), Err(RuntimeError::PointerOutOfBounds)));
+}
+
+#[test]
+fn footprint_past_the_tape_stops()
+{
+    let source = format!("+.{}+.", ">".repeat(TAPE_SIZE));
+    assert!(matches!(run(&source), Err(RuntimeError::PointerOutOfBounds)));
+}
+
+#[test]
+fn footprint_within_the_tape_runs()
+{
+    let source = format!("{}+.", ">".repeat(TAPE_SIZE - 1));
+    assert_eq!(run(&source).ok(), Some(vec![1]));
+}
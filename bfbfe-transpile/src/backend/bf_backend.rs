@@ -0,0 +1,149 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+use bfbfe_ir::optimization::knowledge::Knowledge;
+
+use crate::codegen_error::CodegenError;
+
+/// Lowers a [`IRBlock`] back into plain Brainfuck.
+///
+/// Pointer movement is deferred the same way `reorder_instructions` defers
+/// it: the emitted tape head only gets moved when a cell actually has to be
+/// touched, and is only synchronized with the logical pointer at loop
+/// boundaries.
+///
+/// Literal output goes through the known cell closest to the tape head,
+/// which is set back to its value afterwards. Literals that the optimizer
+/// creates always have one, since it only creates them from cells whose
+/// values it knows, or at the start of a program, where the tape is zeroed.
+pub fn compile_to_bf(block: &IRBlock) -> Result<String, CodegenError>
+{
+    let mut code = String::new();
+    let mut cursor = 0_isize;
+
+    _compile_to_bf(block, &mut code, &mut cursor, &mut Knowledge::unknown())?;
+
+    Ok(code)
+}
+
+fn _compile_to_bf(
+    block: &IRBlock,
+    code: &mut String,
+    cursor: &mut isize,
+    knowledge: &mut Knowledge,
+) -> Result<(), CodegenError>
+{
+    // Moves the tape head from the cursor (relative to the logical pointer) to
+    // `$pos`
+    macro_rules! seek {
+        ($pos:expr) => {{
+            let distance: isize = $pos - *cursor;
+            code.push_str(&if distance.is_negative() { "<" } else { ">" }.repeat(distance.unsigned_abs()));
+            *cursor = $pos;
+        }};
+    }
+
+    // Adds `$value` to the cell under the tape head, picking whichever of `+`
+    // and `-` gives the shorter run modulo 256
+    macro_rules! mutate {
+        ($value:expr) => {{
+            let value = $value.rem_euclid(256).unsigned_abs();
+            if value <= 128 {
+                code.push_str(&"+".repeat(value));
+            } else {
+                code.push_str(&"-".repeat(256 - value));
+            }
+        }};
+    }
+
+    for instr in block.iter() {
+        match instr {
+            IRInstruction::BeginProgram => {}
+
+            IRInstruction::EndProgram => {
+                code.push('\n');
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                *cursor -= val;
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                seek!(*pos);
+                mutate!(val);
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                seek!(*pos);
+                code.push_str("[-]");
+                mutate!(val);
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    seek!(*pos);
+                    code.push('.');
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    seek!(*pos);
+                    code.push(',');
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                let (pos, val) = knowledge.nearest_known(*cursor).ok_or(CodegenError::NoKnownCell)?;
+                seek!(pos);
+
+                let mut previous = val;
+                for &byte in bytes {
+                    mutate!(isize::from(byte) - previous);
+                    code.push('.');
+                    previous = isize::from(byte);
+                }
+
+                code.push_str("[-]");
+                mutate!(val);
+            }
+
+            // The body leaves the cell it ends on at zero, so a loop of it runs once at most
+            IRInstruction::ConditionalBlock(pos, block) | IRInstruction::IfNonZero(pos, block) => {
+                seek!(*pos);
+                code.push('[');
+                _compile_to_bf(block, code, cursor, &mut Knowledge::unknown())?;
+                seek!(*pos);
+                code.push(']');
+            }
+
+            // Passed through for interpreters that support it, and a comment otherwise
+            IRInstruction::Debug => {
+                seek!(0);
+                code.push('#');
+            }
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                return Err(CodegenError::UnrecognizedInstruction);
+            }
+        }
+
+        knowledge.update(instr);
+    }
+
+    Ok(())
+}
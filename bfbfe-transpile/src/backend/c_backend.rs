@@ -1,6 +1,24 @@
 use bfbfe_ir::block::IRBlock;
 use bfbfe_ir::instruction::IRInstruction;
 
+/// The amount of cells the tape has unless the program is known to need less.
+const TAPE_SIZE: usize = 30000;
+
+/// Escapes `bytes` for a C string literal. Octal escapes always take three
+/// digits, so that they can't run into a digit that follows them, and `?` is
+/// escaped so that it can't start a trigraph.
+fn escape(bytes: &[u8]) -> String
+{
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            b'"' | b'\\' | b'?' => format!("\\{}", char::from(byte)),
+            b' '..=b'~' => char::from(byte).to_string(),
+            _ => format!("\\{byte:03o}"),
+        })
+        .collect()
+}
+
 pub fn compile_to_c(block: &IRBlock) -> String
 {
     let mut code = String::new();
@@ -56,21 +74,27 @@ fn _compile_to_c(block: &IRBlock, code: &mut String, indent_level: &mut usize)
 
     for instr in block.iter().by_ref() {
         match instr {
+            // Programs that can't leave a smaller part of the tape only get that part of it
             IRInstruction::BeginProgram => {
-                push_raw!(
+                let tape_size = block
+                    .footprint()
+                    .cells()
+                    .map_or(TAPE_SIZE, |cells| cells.end.min(TAPE_SIZE));
+                push_raw!(format!(
                     "#include <stddef.h>
 #include <stdint.h>
 #include <stdio.h>
 
-#define TAPE_SIZE 30000
+#define TAPE_SIZE {tape_size}
 
 int main(void)
-{
-    uint8_t tape[TAPE_SIZE] = { 0 };
+{{
+    uint8_t tape[TAPE_SIZE] = {{ 0 }};
     size_t ptr = 0;
 
 "
-                );
+                )
+                .as_str());
             }
 
             IRInstruction::EndProgram => {
@@ -123,14 +147,46 @@ int main(void)
                 }
             }
 
-            IRInstruction::ConditionalBlock(block) => {
-                push!("while (tape[ptr] != 0) {\n");
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                push!(format!("fwrite(\"{}\", 1, {}, stdout);\n", escape(bytes), bytes.len()));
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                push!(format!("while ({} != 0) {{\n", access_value!(*pos)));
                 *indent_level += 1;
                 _compile_to_c(block, code, indent_level);
                 *indent_level -= 1;
                 push!("}\n");
             }
 
+            IRInstruction::IfNonZero(pos, block) => {
+                push!(format!("if ({} != 0) {{\n", access_value!(*pos)));
+                *indent_level += 1;
+                _compile_to_c(block, code, indent_level);
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
+            // Dumps the tape up to the pointer or the last non-zero cell, whichever is further
+            IRInstruction::Debug => {
+                push!("{\n");
+                *indent_level += 1;
+                push!("size_t end = ptr;\n");
+                push!("for (size_t i = ptr; i < TAPE_SIZE; i++) {\n");
+                push!("    if (tape[i] != 0) end = i;\n");
+                push!("}\n");
+                push!("fflush(stdout);\n");
+                push!("fprintf(stderr, \"# pointer at cell %zu:\", ptr);\n");
+                push!("for (size_t i = 0; i <= end; i++) {\n");
+                push!("    fprintf(stderr, \" %u\", (unsigned) tape[i]);\n");
+                push!("}\n");
+                push!("fputc('\\n', stderr);\n");
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
             // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
             _ => {
                 panic!("Unrecognized instruction");
@@ -0,0 +1,181 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+
+use super::escape;
+use crate::codegen_error::CodegenError;
+
+/// Lowers a [`IRBlock`] to a standalone Go program.
+///
+/// The tape and EOF behavior match the C backend: 30000 cells, and reading
+/// past the end of input stores 255.
+pub fn compile_to_go(block: &IRBlock) -> Result<String, CodegenError>
+{
+    let mut code = String::new();
+    let mut indent_level = 1_usize;
+
+    _compile_to_go(block, &mut code, &mut indent_level)?;
+
+    Ok(code)
+}
+
+fn _compile_to_go(block: &IRBlock, code: &mut String, indent_level: &mut usize) -> Result<(), CodegenError>
+{
+    macro_rules! indent {
+        ($level:expr, $content:expr) => {
+            format!("{}{}", "\t".repeat($level), $content)
+        };
+    }
+
+    macro_rules! push_raw {
+        ($content:expr) => {
+            code.push_str($content)
+        };
+    }
+
+    macro_rules! push {
+        ($content:expr) => {
+            push_raw!(indent!(*indent_level, $content).as_str())
+        };
+    }
+
+    macro_rules! sign {
+        ($value:expr) => {
+            if $value.is_negative() {
+                "-"
+            } else {
+                "+"
+            }
+        };
+    }
+
+    macro_rules! access_value {
+        ($shift:expr) => {
+            format!(
+                "tape[ptr{}]",
+                if $shift == 0 {
+                    String::new()
+                } else {
+                    format!("{}{}", sign!($shift), $shift.abs())
+                }
+            )
+        };
+    }
+
+    for instr in block.iter().by_ref() {
+        match instr {
+            IRInstruction::BeginProgram => {
+                push_raw!(
+                    "package main
+
+import (
+\t\"bufio\"
+\t\"os\"
+)
+
+const tapeSize = 30000
+
+var (
+\tstdin  = bufio.NewReader(os.Stdin)
+\tstdout = bufio.NewWriter(os.Stdout)
+)
+
+func readByte() byte {
+\tstdout.Flush()
+\tb, err := stdin.ReadByte()
+\tif err != nil {
+\t\treturn 255
+\t}
+\treturn b
+}
+
+func main() {
+\tdefer stdout.Flush()
+
+\ttape := make([]byte, tapeSize)
+\tptr := 0
+\t_, _ = tape, ptr // Programs are not required to touch the tape
+
+"
+                );
+            }
+
+            IRInstruction::EndProgram => {
+                push_raw!("}\n");
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                push!(format!("ptr {}= {}\n", sign!(val), val.abs()));
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                // Byte constants have to fit in a byte, so pick the shorter direction modulo
+                // 256
+                let val = val.rem_euclid(256);
+                if val <= 128 {
+                    push!(format!("{} += {val}\n", access_value!(*pos)));
+                } else {
+                    push!(format!("{} -= {}\n", access_value!(*pos), 256 - val));
+                }
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                push!(format!("{} = {}\n", access_value!(*pos), val.rem_euclid(256)));
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    push!(format!("stdout.WriteByte({})\n", access_value!(*pos)));
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    push!(format!("{} = readByte()\n", access_value!(*pos)));
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                push!(format!("stdout.WriteString(\"{}\")\n", escape(bytes)));
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                push!(format!("for {} != 0 {{\n", access_value!(*pos)));
+                *indent_level += 1;
+                _compile_to_go(block, code, indent_level)?;
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                push!(format!("if {} != 0 {{\n", access_value!(*pos)));
+                *indent_level += 1;
+                _compile_to_go(block, code, indent_level)?;
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
+            IRInstruction::Debug => {}
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                return Err(CodegenError::UnrecognizedInstruction);
+            }
+        }
+    }
+
+    Ok(())
+}
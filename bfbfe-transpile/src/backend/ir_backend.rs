@@ -0,0 +1,121 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+
+/// Lists a [`IRBlock`] as text, one instruction per line with the bodies of
+/// loops indented, for inspecting what the optimizer produced.
+///
+/// Positions relative to the pointer are written in brackets, and values are
+/// written as they are stored, without wrapping them to a byte.
+pub fn compile_to_ir(block: &IRBlock) -> String
+{
+    let mut code = String::new();
+    let mut indent_level = 0_usize;
+
+    _compile_to_ir(block, &mut code, &mut indent_level);
+
+    code
+}
+
+fn _compile_to_ir(block: &IRBlock, code: &mut String, indent_level: &mut usize)
+{
+    macro_rules! push {
+        ($content:expr) => {
+            code.push_str(&format!("{}{}\n", "    ".repeat(*indent_level), $content))
+        };
+    }
+
+    macro_rules! signed {
+        ($value:expr) => {
+            if *$value > 0 {
+                format!("+{}", $value)
+            } else {
+                $value.to_string()
+            }
+        };
+    }
+
+    macro_rules! positions {
+        ($poslst:expr) => {
+            $poslst
+                .iter()
+                .map(|pos| format!("[{}]", signed!(pos)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+    }
+
+    for instr in block.iter() {
+        match instr {
+            IRInstruction::BeginProgram => {
+                push!("begin_program");
+            }
+
+            IRInstruction::EndProgram => {
+                push!("end_program");
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                push!(format!("traverse_by {}", signed!(val)));
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                push!(format!("mutate_value [{}] {}", signed!(pos), signed!(val)));
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                push!(format!("set_to [{}] {val}", signed!(pos)));
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                push!(format!("output_bytes {}", positions!(poslst)));
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                push!(format!("read_bytes {}", positions!(poslst)));
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                push!(format!("output_literal \"{}\"", bytes.escape_ascii()));
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                push!(format!("conditional_block [{}] {{", signed!(pos)));
+                *indent_level += 1;
+                _compile_to_ir(block, code, indent_level);
+                *indent_level -= 1;
+                push!("}");
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                push!(format!("if_non_zero [{}] {{", signed!(pos)));
+                *indent_level += 1;
+                _compile_to_ir(block, code, indent_level);
+                *indent_level -= 1;
+                push!("}");
+            }
+
+            IRInstruction::Debug => {
+                push!("debug");
+            }
+
+            // BFBFE IR is subject to expansion, so instead of listing it wrongly, fail
+            _ => {
+                panic!("Unrecognized instruction");
+            }
+        }
+    }
+}
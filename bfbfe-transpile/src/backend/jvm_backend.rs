@@ -0,0 +1,518 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+
+use crate::codegen_error::CodegenError;
+
+/// Name of the emitted class. The class file has to be written to
+/// `Main.class` for the JVM to load it.
+const CLASS_NAME: &str = "Main";
+
+/// Local variable slot of the pointer. `main` keeps its arguments in slot 0,
+/// the tape in slot 1 and the pointer in slot 2.
+const PTR_SLOT: u8 = 2;
+
+const MAX_STACK: u16 = 4;
+const MAX_LOCALS: u16 = 3;
+
+/// The longest code attribute the JVM accepts for a single method.
+const MAX_CODE_LENGTH: usize = 0xFFFF;
+
+/// Reports a value that doesn't fit in the field of the class file it goes
+/// into, described by `what`.
+fn too_large<E>(what: &'static str) -> impl FnOnce(E) -> CodegenError
+{
+    move |_| CodegenError::TooLarge(what)
+}
+
+mod opcode
+{
+    pub const ICONST_0: u8 = 0x03;
+    pub const BIPUSH: u8 = 0x10;
+    pub const SIPUSH: u8 = 0x11;
+    pub const LDC_W: u8 = 0x13;
+    pub const ALOAD_1: u8 = 0x2B;
+    pub const ILOAD_2: u8 = 0x1C;
+    pub const BALOAD: u8 = 0x33;
+    pub const ASTORE_1: u8 = 0x4C;
+    pub const ISTORE_2: u8 = 0x3D;
+    pub const BASTORE: u8 = 0x54;
+    pub const DUP2: u8 = 0x5C;
+    pub const IADD: u8 = 0x60;
+    pub const IINC: u8 = 0x84;
+    pub const IFEQ: u8 = 0x99;
+    pub const IFNE: u8 = 0x9A;
+    pub const GOTO: u8 = 0xA7;
+    pub const RETURN: u8 = 0xB1;
+    pub const GETSTATIC: u8 = 0xB2;
+    pub const INVOKEVIRTUAL: u8 = 0xB6;
+    pub const NEWARRAY: u8 = 0xBC;
+    pub const WIDE: u8 = 0xC4;
+    pub const GOTO_W: u8 = 0xC8;
+
+    pub const T_BYTE: u8 = 8;
+}
+
+/// Deduplicating builder for the constant pool of a class file.
+struct ConstantPool
+{
+    bytes:   Vec<u8>,
+    entries: Vec<Vec<u8>>,
+}
+
+impl ConstantPool
+{
+    const fn new() -> Self
+    {
+        Self {
+            bytes:   Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, entry: Vec<u8>) -> Result<u16, CodegenError>
+    {
+        let index = self.entries.iter().position(|e| *e == entry).unwrap_or_else(|| {
+            self.bytes.extend(&entry);
+            self.entries.push(entry);
+            self.entries.len() - 1
+        });
+
+        // Constant pool indices start at 1
+        u16::try_from(index + 1).map_err(too_large("constant pool overflow"))
+    }
+
+    fn utf8(&mut self, s: &str) -> Result<u16, CodegenError>
+    {
+        let mut entry = vec![1];
+        entry.extend(
+            u16::try_from(s.len())
+                .map_err(too_large("string constant too long"))?
+                .to_be_bytes(),
+        );
+        entry.extend(s.as_bytes());
+        self.insert(entry)
+    }
+
+    fn integer(&mut self, val: i32) -> Result<u16, CodegenError>
+    {
+        let mut entry = vec![3];
+        entry.extend(val.to_be_bytes());
+        self.insert(entry)
+    }
+
+    fn class(&mut self, name: &str) -> Result<u16, CodegenError>
+    {
+        let name = self.utf8(name)?;
+        let mut entry = vec![7];
+        entry.extend(name.to_be_bytes());
+        self.insert(entry)
+    }
+
+    fn name_and_type(&mut self, name: &str, descriptor: &str) -> Result<u16, CodegenError>
+    {
+        let name = self.utf8(name)?;
+        let descriptor = self.utf8(descriptor)?;
+        let mut entry = vec![12];
+        entry.extend(name.to_be_bytes());
+        entry.extend(descriptor.to_be_bytes());
+        self.insert(entry)
+    }
+
+    fn member_ref(&mut self, tag: u8, class: &str, name: &str, descriptor: &str) -> Result<u16, CodegenError>
+    {
+        let class = self.class(class)?;
+        let name_and_type = self.name_and_type(name, descriptor)?;
+        let mut entry = vec![tag];
+        entry.extend(class.to_be_bytes());
+        entry.extend(name_and_type.to_be_bytes());
+        self.insert(entry)
+    }
+
+    fn field_ref(&mut self, class: &str, name: &str, descriptor: &str) -> Result<u16, CodegenError>
+    {
+        self.member_ref(9, class, name, descriptor)
+    }
+
+    fn method_ref(&mut self, class: &str, name: &str, descriptor: &str) -> Result<u16, CodegenError>
+    {
+        self.member_ref(10, class, name, descriptor)
+    }
+
+    fn count(&self) -> Result<u16, CodegenError>
+    {
+        u16::try_from(self.entries.len() + 1).map_err(too_large("constant pool overflow"))
+    }
+}
+
+/// Bytecode of a block along with the offsets that need a stack map frame
+/// because they are branch targets.
+struct Code
+{
+    bytes:  Vec<u8>,
+    frames: Vec<usize>,
+}
+
+/// Lowers a [`IRBlock`] to a JVM class file named `Main` with a single `main`
+/// method.
+///
+/// The tape is a `byte[]` of 30000 cells and I/O goes through `System.in` and
+/// `System.out`, with reading past the end of input storing 255, matching the
+/// C backend. The class file targets Java 8, so every loop boundary is
+/// described by a stack map frame.
+///
+/// Everything is compiled into `main`, so programs whose code is longer than
+/// a single method allows fail with [`CodegenError::TooLarge`].
+pub fn compile_to_jvm(block: &IRBlock) -> Result<Vec<u8>, CodegenError>
+{
+    let mut pool = ConstantPool::new();
+
+    let this_class = pool.class(CLASS_NAME)?;
+    let super_class = pool.class("java/lang/Object")?;
+    let main_name = pool.utf8("main")?;
+    let main_descriptor = pool.utf8("([Ljava/lang/String;)V")?;
+    let code_name = pool.utf8("Code")?;
+    let stack_map_table_name = pool.utf8("StackMapTable")?;
+    let tape_class = pool.class("[B")?;
+
+    let mut code = _compile_to_jvm(block, &mut pool)?;
+    if code.bytes.len() > MAX_CODE_LENGTH {
+        return Err(CodegenError::TooLarge("code longer than a single JVM method allows"));
+    }
+
+    code.frames.sort_unstable();
+    code.frames.dedup();
+
+    // Every frame has the same locals (String[] args, byte[] tape, int ptr) and an
+    // empty stack, so the first frame appends the tape and pointer to the
+    // implicit initial frame and every other frame is a same_frame
+    let mut stack_map_table = Vec::<u8>::new();
+    let mut previous: Option<usize> = None;
+    for &offset in &code.frames {
+        let delta = previous.map_or(offset, |previous| offset - previous - 1);
+        let delta = u16::try_from(delta).map_err(too_large("stack map frame offset overflow"))?;
+
+        if previous.is_none() {
+            stack_map_table.push(253);
+            stack_map_table.extend(delta.to_be_bytes());
+            stack_map_table.push(7);
+            stack_map_table.extend(tape_class.to_be_bytes());
+            stack_map_table.push(1);
+        } else if let Ok(delta @ 0..=63) = u8::try_from(delta) {
+            stack_map_table.push(delta);
+        } else {
+            stack_map_table.push(251);
+            stack_map_table.extend(delta.to_be_bytes());
+        }
+
+        previous = Some(offset);
+    }
+
+    let mut code_attribute = Vec::<u8>::new();
+    code_attribute.extend(MAX_STACK.to_be_bytes());
+    code_attribute.extend(MAX_LOCALS.to_be_bytes());
+    code_attribute.extend(
+        u32::try_from(code.bytes.len())
+            .map_err(too_large("code too long"))?
+            .to_be_bytes(),
+    );
+    code_attribute.extend(&code.bytes);
+    // Exception table
+    code_attribute.extend(0_u16.to_be_bytes());
+    if code.frames.is_empty() {
+        code_attribute.extend(0_u16.to_be_bytes());
+    } else {
+        code_attribute.extend(1_u16.to_be_bytes());
+        code_attribute.extend(stack_map_table_name.to_be_bytes());
+        code_attribute.extend(
+            u32::try_from(stack_map_table.len() + 2)
+                .map_err(too_large("code too long"))?
+                .to_be_bytes(),
+        );
+        code_attribute.extend(
+            u16::try_from(code.frames.len())
+                .map_err(too_large("too many frames"))?
+                .to_be_bytes(),
+        );
+        code_attribute.extend(stack_map_table);
+    }
+
+    let mut class = Vec::<u8>::new();
+    class.extend(0xCAFE_BABE_u32.to_be_bytes());
+    // Java 8
+    class.extend(0_u16.to_be_bytes());
+    class.extend(52_u16.to_be_bytes());
+    class.extend(pool.count()?.to_be_bytes());
+    class.extend(&pool.bytes);
+    // ACC_PUBLIC | ACC_SUPER
+    class.extend(0x0021_u16.to_be_bytes());
+    class.extend(this_class.to_be_bytes());
+    class.extend(super_class.to_be_bytes());
+    // Interfaces and fields
+    class.extend(0_u16.to_be_bytes());
+    class.extend(0_u16.to_be_bytes());
+    // The main method, ACC_PUBLIC | ACC_STATIC
+    class.extend(1_u16.to_be_bytes());
+    class.extend(0x0009_u16.to_be_bytes());
+    class.extend(main_name.to_be_bytes());
+    class.extend(main_descriptor.to_be_bytes());
+    class.extend(1_u16.to_be_bytes());
+    class.extend(code_name.to_be_bytes());
+    class.extend(
+        u32::try_from(code_attribute.len())
+            .map_err(too_large("code too long"))?
+            .to_be_bytes(),
+    );
+    class.extend(code_attribute);
+    // Class attributes
+    class.extend(0_u16.to_be_bytes());
+
+    Ok(class)
+}
+
+fn _compile_to_jvm(block: &IRBlock, pool: &mut ConstantPool) -> Result<Code, CodegenError>
+{
+    let mut code = Code {
+        bytes:  Vec::new(),
+        frames: Vec::new(),
+    };
+
+    macro_rules! push {
+        ($($byte:expr),+) => {
+            code.bytes.extend([$($byte),+])
+        };
+    }
+
+    macro_rules! push_u16 {
+        ($value:expr) => {
+            code.bytes.extend(u16::to_be_bytes($value))
+        };
+    }
+
+    macro_rules! push_int {
+        ($value:expr) => {{
+            let value = i32::try_from($value).map_err(too_large("constant does not fit in a JVM int"))?;
+            if let Ok(value @ 0..=5) = u8::try_from(value) {
+                push!(opcode::ICONST_0 + value);
+            } else if let Ok(value) = i8::try_from(value) {
+                push!(opcode::BIPUSH);
+                code.bytes.extend(value.to_be_bytes());
+            } else if let Ok(value) = i16::try_from(value) {
+                push!(opcode::SIPUSH);
+                code.bytes.extend(value.to_be_bytes());
+            } else {
+                let index = pool.integer(value)?;
+                push!(opcode::LDC_W);
+                push_u16!(index);
+            }
+        }};
+    }
+
+    // Pushes the tape and the index `$shift` positions from the pointer
+    macro_rules! access_value {
+        ($shift:expr) => {
+            push!(opcode::ALOAD_1, opcode::ILOAD_2);
+            if $shift != 0 {
+                push_int!($shift);
+                push!(opcode::IADD);
+            }
+        };
+    }
+
+    // Truncates `$value` to the range of a signed byte with the same bits
+    macro_rules! byte {
+        ($value:expr) => {{
+            let value = $value.rem_euclid(256);
+            if value > 127 {
+                value - 256
+            } else {
+                value
+            }
+        }};
+    }
+
+    for instr in block.iter().by_ref() {
+        match instr {
+            IRInstruction::BeginProgram => {
+                push_int!(30_000_i32);
+                push!(opcode::NEWARRAY, opcode::T_BYTE, opcode::ASTORE_1);
+                push!(opcode::ICONST_0, opcode::ISTORE_2);
+            }
+
+            IRInstruction::EndProgram => {
+                let stdout = pool.field_ref("java/lang/System", "out", "Ljava/io/PrintStream;")?;
+                let flush = pool.method_ref("java/io/PrintStream", "flush", "()V")?;
+                push!(opcode::GETSTATIC);
+                push_u16!(stdout);
+                push!(opcode::INVOKEVIRTUAL);
+                push_u16!(flush);
+                push!(opcode::RETURN);
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                if let Ok(val) = i8::try_from(*val) {
+                    push!(opcode::IINC, PTR_SLOT);
+                    code.bytes.extend(val.to_be_bytes());
+                } else if let Ok(val) = i16::try_from(*val) {
+                    push!(opcode::WIDE, opcode::IINC);
+                    push_u16!(PTR_SLOT.into());
+                    code.bytes.extend(val.to_be_bytes());
+                } else {
+                    push!(opcode::ILOAD_2);
+                    push_int!(*val);
+                    push!(opcode::IADD, opcode::ISTORE_2);
+                }
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                access_value!(*pos);
+                push!(opcode::DUP2, opcode::BALOAD);
+                push_int!(byte!(val));
+                push!(opcode::IADD, opcode::BASTORE);
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                access_value!(*pos);
+                push_int!(byte!(val));
+                push!(opcode::BASTORE);
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                let stdout = pool.field_ref("java/lang/System", "out", "Ljava/io/PrintStream;")?;
+                let write = pool.method_ref("java/io/PrintStream", "write", "(I)V")?;
+                for pos in poslst {
+                    push!(opcode::GETSTATIC);
+                    push_u16!(stdout);
+                    access_value!(*pos);
+                    push!(opcode::BALOAD, opcode::INVOKEVIRTUAL);
+                    push_u16!(write);
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                let stdout = pool.field_ref("java/lang/System", "out", "Ljava/io/PrintStream;")?;
+                let write = pool.method_ref("java/io/PrintStream", "write", "(I)V")?;
+                for byte in bytes {
+                    push!(opcode::GETSTATIC);
+                    push_u16!(stdout);
+                    push_int!(*byte);
+                    push!(opcode::INVOKEVIRTUAL);
+                    push_u16!(write);
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                let stdout = pool.field_ref("java/lang/System", "out", "Ljava/io/PrintStream;")?;
+                let flush = pool.method_ref("java/io/PrintStream", "flush", "()V")?;
+                let stdin = pool.field_ref("java/lang/System", "in", "Ljava/io/InputStream;")?;
+                let read = pool.method_ref("java/io/InputStream", "read", "()I")?;
+                for pos in poslst {
+                    // Make sure prompts are visible before blocking on input
+                    push!(opcode::GETSTATIC);
+                    push_u16!(stdout);
+                    push!(opcode::INVOKEVIRTUAL);
+                    push_u16!(flush);
+                    access_value!(*pos);
+                    push!(opcode::GETSTATIC);
+                    push_u16!(stdin);
+                    push!(opcode::INVOKEVIRTUAL);
+                    push_u16!(read);
+                    push!(opcode::BASTORE);
+                }
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                let body = _compile_to_jvm(block, pool)?;
+                let start = code.bytes.len();
+
+                code.frames.push(start);
+                access_value!(*pos);
+                push!(opcode::BALOAD);
+                let header = code.bytes.len() - start;
+
+                // Branch offsets are relative to the branching instruction. Loops that are too
+                // long for 16-bit offsets branch over a wide jump instead, and
+                // jump back with a wide jump as well.
+                if let Ok(len @ 0..=0x7000) = i16::try_from(body.bytes.len()) {
+                    push!(opcode::IFEQ);
+                    code.bytes.extend((len + 6).to_be_bytes());
+
+                    let body_start = code.bytes.len();
+                    code.frames.extend(body.frames.iter().map(|frame| frame + body_start));
+                    code.bytes.extend(&body.bytes);
+
+                    let header = i16::try_from(header).map_err(too_large("condition too long"))?;
+                    push!(opcode::GOTO);
+                    code.bytes.extend((-(len + header + 3)).to_be_bytes());
+                } else {
+                    let len = i32::try_from(body.bytes.len()).map_err(too_large("loop too long"))?;
+                    push!(opcode::IFNE);
+                    code.bytes.extend(8_i16.to_be_bytes());
+                    push!(opcode::GOTO_W);
+                    code.bytes.extend((len + 10).to_be_bytes());
+
+                    let body_start = code.bytes.len();
+                    code.frames.push(body_start);
+                    code.frames.extend(body.frames.iter().map(|frame| frame + body_start));
+                    code.bytes.extend(&body.bytes);
+
+                    let header = i32::try_from(header).map_err(too_large("condition too long"))?;
+                    push!(opcode::GOTO_W);
+                    code.bytes.extend((-(len + header + 8)).to_be_bytes());
+                }
+
+                code.frames.push(code.bytes.len());
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                let body = _compile_to_jvm(block, pool)?;
+
+                access_value!(*pos);
+                push!(opcode::BALOAD);
+
+                // Bodies that are too long for a 16-bit offset are branched over with a wide
+                // jump instead
+                if let Ok(len @ 0..=0x7000) = i16::try_from(body.bytes.len()) {
+                    push!(opcode::IFEQ);
+                    code.bytes.extend((len + 3).to_be_bytes());
+                } else {
+                    let len = i32::try_from(body.bytes.len()).map_err(too_large("block too long"))?;
+                    push!(opcode::IFNE);
+                    code.bytes.extend(8_i16.to_be_bytes());
+                    push!(opcode::GOTO_W);
+                    code.bytes.extend((len + 5).to_be_bytes());
+
+                    code.frames.push(code.bytes.len());
+                }
+
+                let body_start = code.bytes.len();
+                code.frames.extend(body.frames.iter().map(|frame| frame + body_start));
+                code.bytes.extend(&body.bytes);
+
+                code.frames.push(code.bytes.len());
+            }
+
+            IRInstruction::Debug => {}
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                return Err(CodegenError::UnrecognizedInstruction);
+            }
+        }
+    }
+
+    Ok(code)
+}
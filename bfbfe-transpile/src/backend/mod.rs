@@ -1,4 +1,10 @@
+pub mod bf_backend;
 pub mod c_backend;
+pub mod go_backend;
+pub mod ir_backend;
+pub mod jvm_backend;
+pub mod qbe_backend;
+pub mod zig_backend;
 
 use core::str::FromStr;
 
@@ -11,6 +17,12 @@ use crate::codegen_error::CodegenError;
 pub enum CompilerBackend
 {
     C,
+    Brainfuck,
+    Qbe,
+    Go,
+    Zig,
+    Jvm,
+    Ir,
 }
 
 impl FromStr for CompilerBackend
@@ -21,14 +33,40 @@ impl FromStr for CompilerBackend
     {
         match s {
             "c" | "C" => Ok(Self::C),
+            "bf" | "BF" => Ok(Self::Brainfuck),
+            "qbe" | "QBE" => Ok(Self::Qbe),
+            "go" | "Go" => Ok(Self::Go),
+            "zig" | "Zig" => Ok(Self::Zig),
+            "jvm" | "JVM" => Ok(Self::Jvm),
+            "ir" | "IR" => Ok(Self::Ir),
             _ => Err(CodegenError::InvalidBackend),
         }
     }
 }
 
-pub const fn get_compiler_fn(backend: &CompilerBackend) -> fn(&IRBlock) -> String
+/// Escapes `bytes` for a string literal in Go or Zig, which share the same
+/// escape sequences.
+fn escape(bytes: &[u8]) -> String
+{
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            b'"' | b'\\' => format!("\\{}", char::from(byte)),
+            b' '..=b'~' => char::from(byte).to_string(),
+            _ => format!("\\x{byte:02x}"),
+        })
+        .collect()
+}
+
+pub const fn get_compiler_fn(backend: &CompilerBackend) -> fn(&IRBlock) -> Result<Vec<u8>, CodegenError>
 {
     match *backend {
-        CompilerBackend::C => c_backend::compile_to_c,
+        CompilerBackend::C => |block| Ok(c_backend::compile_to_c(block).into_bytes()),
+        CompilerBackend::Brainfuck => |block| bf_backend::compile_to_bf(block).map(String::into_bytes),
+        CompilerBackend::Qbe => |block| Ok(qbe_backend::compile_to_qbe(block).into_bytes()),
+        CompilerBackend::Go => |block| go_backend::compile_to_go(block).map(String::into_bytes),
+        CompilerBackend::Zig => |block| zig_backend::compile_to_zig(block).map(String::into_bytes),
+        CompilerBackend::Jvm => jvm_backend::compile_to_jvm,
+        CompilerBackend::Ir => |block| Ok(ir_backend::compile_to_ir(block).into_bytes()),
     }
 }
@@ -0,0 +1,154 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+
+/// Lowers a [`IRBlock`] to QBE IL.
+///
+/// The tape is a zero-initialized data section and the pointer lives in the
+/// `%ptr` temporary. Temporaries are freely reassigned since QBE performs SSA
+/// construction itself.
+pub fn compile_to_qbe(block: &IRBlock) -> String
+{
+    let mut code = String::new();
+    let mut label_count = 0_usize;
+
+    _compile_to_qbe(block, &mut code, &mut label_count);
+
+    code
+}
+
+fn _compile_to_qbe(block: &IRBlock, code: &mut String, label_count: &mut usize)
+{
+    macro_rules! push_raw {
+        ($content:expr) => {
+            code.push_str($content)
+        };
+    }
+
+    macro_rules! push {
+        ($content:expr) => {
+            push_raw!(format!("\t{}", $content).as_str())
+        };
+    }
+
+    // Loads the address of the value `$shift` positions from the pointer into
+    // `%addr` and evaluates to the name of the temporary holding it
+    macro_rules! address_of {
+        ($shift:expr) => {
+            if $shift == 0 {
+                "%ptr"
+            } else {
+                push!(format!("%addr =l add %ptr, {}\n", $shift));
+                "%addr"
+            }
+        };
+    }
+
+    for instr in block.iter().by_ref() {
+        match instr {
+            IRInstruction::BeginProgram => {
+                push_raw!(
+                    "data $tape = { z 30000 }
+
+export function w $main()
+{
+@start
+\t%ptr =l copy $tape
+"
+                );
+            }
+
+            IRInstruction::EndProgram => {
+                push_raw!(
+                    "\tret 0
+}
+"
+                );
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                push!(format!("%ptr =l add %ptr, {val}\n"));
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                let addr = address_of!(*pos);
+                push!(format!("%val =w loadub {addr}\n"));
+                push!(format!("%val =w add %val, {val}\n"));
+                push!(format!("storeb %val, {addr}\n"));
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                let addr = address_of!(*pos);
+                push!(format!("storeb {val}, {addr}\n"));
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    let addr = address_of!(*pos);
+                    push!(format!("%val =w loadub {addr}\n"));
+                    push!("call $putchar(w %val)\n");
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                for byte in bytes {
+                    push!(format!("call $putchar(w {byte})\n"));
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    let addr = address_of!(*pos);
+                    push!("%val =w call $getchar()\n");
+                    push!(format!("storeb %val, {addr}\n"));
+                }
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                let label = *label_count;
+                *label_count += 1;
+
+                push_raw!(format!("@loop{label}\n").as_str());
+                let addr = address_of!(*pos);
+                push!(format!("%val =w loadub {addr}\n"));
+                push!(format!("jnz %val, @body{label}, @end{label}\n"));
+                push_raw!(format!("@body{label}\n").as_str());
+                _compile_to_qbe(block, code, label_count);
+                push!(format!("jmp @loop{label}\n"));
+                push_raw!(format!("@end{label}\n").as_str());
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                let label = *label_count;
+                *label_count += 1;
+
+                let addr = address_of!(*pos);
+                push!(format!("%val =w loadub {addr}\n"));
+                push!(format!("jnz %val, @body{label}, @end{label}\n"));
+                push_raw!(format!("@body{label}\n").as_str());
+                _compile_to_qbe(block, code, label_count);
+                push_raw!(format!("@end{label}\n").as_str());
+            }
+
+            IRInstruction::Debug => {}
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                panic!("Unrecognized instruction");
+            }
+        }
+    }
+}
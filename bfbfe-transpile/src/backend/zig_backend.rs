@@ -0,0 +1,176 @@
+use bfbfe_ir::block::IRBlock;
+use bfbfe_ir::instruction::IRInstruction;
+
+use super::escape;
+use crate::codegen_error::CodegenError;
+
+/// Lowers a [`IRBlock`] to a standalone Zig program.
+///
+/// The tape and EOF behavior match the C backend: 30000 cells, and reading
+/// past the end of input stores 255. The emitted code targets the Zig 0.13
+/// standard library.
+pub fn compile_to_zig(block: &IRBlock) -> Result<String, CodegenError>
+{
+    let mut code = String::new();
+    let mut indent_level = 1_usize;
+
+    _compile_to_zig(block, &mut code, &mut indent_level)?;
+
+    Ok(code)
+}
+
+fn _compile_to_zig(block: &IRBlock, code: &mut String, indent_level: &mut usize) -> Result<(), CodegenError>
+{
+    macro_rules! indent {
+        ($level:expr, $content:expr) => {
+            format!("{}{}", "    ".repeat($level), $content)
+        };
+    }
+
+    macro_rules! push_raw {
+        ($content:expr) => {
+            code.push_str($content)
+        };
+    }
+
+    macro_rules! push {
+        ($content:expr) => {
+            push_raw!(indent!(*indent_level, $content).as_str())
+        };
+    }
+
+    macro_rules! sign {
+        ($value:expr) => {
+            if $value.is_negative() {
+                "-"
+            } else {
+                "+"
+            }
+        };
+    }
+
+    macro_rules! access_value {
+        ($shift:expr) => {
+            format!(
+                "tape[ptr{}]",
+                if $shift == 0 {
+                    String::new()
+                } else {
+                    format!(" {} {}", sign!($shift), $shift.abs())
+                }
+            )
+        };
+    }
+
+    for instr in block.iter().by_ref() {
+        match instr {
+            IRInstruction::BeginProgram => {
+                push_raw!(
+                    "const std = @import(\"std\");
+
+const tape_size = 30000;
+const Output = std.io.BufferedWriter(4096, std.fs.File.Writer);
+
+var tape = [_]u8{0} ** tape_size;
+var ptr: usize = 0;
+
+fn readByte(out: *Output) u8 {
+    out.flush() catch {};
+    return std.io.getStdIn().reader().readByte() catch 255;
+}
+
+fn writeByte(out: *Output, byte: u8) void {
+    out.writer().writeByte(byte) catch {};
+}
+
+fn writeBytes(out: *Output, bytes: []const u8) void {
+    out.writer().writeAll(bytes) catch {};
+}
+
+pub fn main() void {
+    var out = std.io.bufferedWriter(std.io.getStdOut().writer());
+    defer out.flush() catch {};
+
+"
+                );
+            }
+
+            IRInstruction::EndProgram => {
+                push_raw!("}\n");
+            }
+
+            IRInstruction::TraverseBy {
+                val,
+            } => {
+                push!(format!("ptr {}= {};\n", sign!(val), val.abs()));
+            }
+
+            IRInstruction::MutateValue {
+                pos,
+                val,
+            } => {
+                // Byte constants have to fit in a u8, so pick the shorter direction modulo 256
+                let val = val.rem_euclid(256);
+                if val <= 128 {
+                    push!(format!("{} +%= {val};\n", access_value!(*pos)));
+                } else {
+                    push!(format!("{} -%= {};\n", access_value!(*pos), 256 - val));
+                }
+            }
+
+            IRInstruction::SetTo {
+                pos,
+                val,
+            } => {
+                push!(format!("{} = {};\n", access_value!(*pos), val.rem_euclid(256)));
+            }
+
+            IRInstruction::OutputBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    push!(format!("writeByte(&out, {});\n", access_value!(*pos)));
+                }
+            }
+
+            IRInstruction::ReadBytes {
+                poslst,
+            } => {
+                for pos in poslst {
+                    push!(format!("{} = readByte(&out);\n", access_value!(*pos)));
+                }
+            }
+
+            IRInstruction::OutputLiteral {
+                bytes,
+            } => {
+                push!(format!("writeBytes(&out, \"{}\");\n", escape(bytes)));
+            }
+
+            IRInstruction::ConditionalBlock(pos, block) => {
+                push!(format!("while ({} != 0) {{\n", access_value!(*pos)));
+                *indent_level += 1;
+                _compile_to_zig(block, code, indent_level)?;
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
+            IRInstruction::IfNonZero(pos, block) => {
+                push!(format!("if ({} != 0) {{\n", access_value!(*pos)));
+                *indent_level += 1;
+                _compile_to_zig(block, code, indent_level)?;
+                *indent_level -= 1;
+                push!("}\n");
+            }
+
+            IRInstruction::Debug => {}
+
+            // BFBFE IR is subject to expansion, so instead of potentially generating invalid code, fail
+            _ => {
+                return Err(CodegenError::UnrecognizedInstruction);
+            }
+        }
+    }
+
+    Ok(())
+}
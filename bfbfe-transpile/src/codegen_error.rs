@@ -7,4 +7,14 @@ pub enum CodegenError
 {
     #[error("invalid backend selected")]
     InvalidBackend,
+    /// The program doesn't fit in what the target can represent, such as the
+    /// size of a single JVM method.
+    #[error("program too large for the target: {0}")]
+    TooLarge(&'static str),
+    /// Literal output in Brainfuck has to go through a cell whose value is
+    /// known at that point.
+    #[error("no cell is known to output a literal through")]
+    NoKnownCell,
+    #[error("unrecognized instruction")]
+    UnrecognizedInstruction,
 }
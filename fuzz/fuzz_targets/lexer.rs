@@ -0,0 +1,32 @@
+#![no_main]
+
+//! Feeds arbitrary text through the lexer and the instructionizer, checking
+//! that neither panics and that every token is located where its character is.
+
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::lexer::tokenize_dialect;
+use bfbfe_lang::lexer::tokenize_with_locations;
+use bfbfe_lang::lexer::Dialect;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| {
+    let chars = source.chars().collect::<Vec<_>>();
+    let dialect = Dialect {
+        debug: true,
+    };
+
+    let tokens = tokenize_dialect(&chars, dialect);
+    let located = tokenize_with_locations(&chars, dialect);
+    assert_eq!(tokens, located.iter().map(|(token, _)| token.clone()).collect::<Vec<_>>());
+    assert!(located.windows(2).all(|pair| matches!(pair, [(_, a), (_, b)] if a < b)));
+
+    for (token, location) in &located {
+        let line = source.lines().nth(location.line - 1).expect("token located past the last line");
+        let ch = line.chars().nth(location.column - 1).expect("token located past the end of its line");
+        assert_eq!(tokenize_dialect(&[ch], dialect), core::slice::from_ref(token));
+    }
+
+    if let Ok(block) = instructionize(&tokens) {
+        let _ = block.optimize();
+    }
+});
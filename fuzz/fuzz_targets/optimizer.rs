@@ -0,0 +1,92 @@
+#![no_main]
+
+//! Generates bracket-balanced programs and checks that optimizing them doesn't
+//! change what they do: whenever the unoptimized program finishes within the
+//! step limit, the optimized program has to finish with the same output, tape
+//! and pointer.
+
+use arbitrary::Arbitrary;
+use bfbfe_lang::instructionize::instructionize;
+use bfbfe_lang::token::Token;
+use bfbfe_runtime::bytecode::Bytecode;
+use bfbfe_runtime::interpreter::Interpreter;
+use bfbfe_runtime::limits::ExecutionLimits;
+use bfbfe_runtime::runtime_error::RuntimeError;
+use libfuzzer_sys::fuzz_target;
+
+/// Keeps programs that never finish from stalling the fuzzer. The optimized
+/// program never executes more operations than the unoptimized one.
+const MAX_STEPS: u64 = 100_000;
+
+/// A Brainfuck command, with loops holding their bodies so that brackets are
+/// always balanced.
+#[derive(Arbitrary, Debug)]
+enum Command
+{
+    Right,
+    Left,
+    Increment,
+    Decrement,
+    Output,
+    Read,
+    Loop(Vec<Self>),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Case
+{
+    program: Vec<Command>,
+    input:   Vec<u8>,
+}
+
+fn tokenize(commands: &[Command], tokens: &mut Vec<Token>)
+{
+    for command in commands {
+        let token = match command {
+            Command::Right => Token::IncrementPointer,
+            Command::Left => Token::DecrementPointer,
+            Command::Increment => Token::IncrementValue,
+            Command::Decrement => Token::DecrementValue,
+            Command::Output => Token::PushByte,
+            Command::Read => Token::ReadByte,
+            Command::Loop(body) => {
+                tokens.push(Token::JumpForward);
+                tokenize(body, tokens);
+                Token::JumpBackward
+            }
+        };
+        tokens.push(token);
+    }
+}
+
+fn run(bytecode: &Bytecode, input: &[u8]) -> Result<(Vec<u8>, Vec<u8>, usize), RuntimeError>
+{
+    let limits = ExecutionLimits {
+        max_steps: Some(MAX_STEPS),
+        ..ExecutionLimits::default()
+    };
+
+    let mut output = Vec::new();
+    let mut interpreter = Interpreter::<u8>::new();
+    interpreter.run_with_limits(bytecode, &mut &*input, &mut output, &limits)?;
+
+    let (tape, ptr) = interpreter.into_parts();
+    Ok((output, tape, ptr))
+}
+
+fuzz_target!(|case: Case| {
+    let mut tokens = Vec::new();
+    tokenize(&case.program, &mut tokens);
+    let block = instructionize(&tokens).expect("generated brackets are balanced");
+
+    let unoptimized = Bytecode::compile_unoptimized(&block).expect("unoptimized IR failed to compile");
+    let Ok(expected) = run(&unoptimized, &case.input) else {
+        return;
+    };
+
+    let optimized = Bytecode::compile(&block.clone().optimize()).expect("optimized IR failed to compile");
+    match run(&optimized, &case.input) {
+        Ok(actual) => assert!(actual == expected, "optimized program diverged\n{block:?}"),
+        Err(e) => panic!("optimized program failed with {e}\n{block:?}"),
+    }
+});